@@ -0,0 +1,44 @@
+use adventofcode2021::prelude::*;
+use criterion::{black_box, criterion_group, criterion_main, Criterion, SamplingMode};
+
+/// Days taking seconds per run, which would need hours with the default sample size
+const SLOW_DAYS: [usize; 3] = [17, 19, 23];
+
+/// Benchmarks parsing, both parts and all alternatives of a day against its puzzle input
+fn bench_day<S: Solution>(c: &mut Criterion) {
+    let data = Input::Puzzle(S::DAY).read().unwrap();
+    let input = S::parse(&data).unwrap();
+
+    let mut group = c.benchmark_group(format!("day{}", S::DAY));
+    if SLOW_DAYS.contains(&S::DAY) {
+        group.sample_size(10).sampling_mode(SamplingMode::Flat);
+    }
+
+    group.bench_function("parse", |b| b.iter(|| S::parse(black_box(&data)).unwrap()));
+    group.bench_function("part1", |b| b.iter(|| S::part1(black_box(&input)).unwrap()));
+    if S::PARTS >= 2 {
+        group.bench_function("part2", |b| b.iter(|| S::part2(black_box(&input)).unwrap()));
+    }
+    for alternative in S::alternatives() {
+        group.bench_function(alternative.name, |b| b.iter(|| (alternative.solve)(black_box(&input)).unwrap()));
+    }
+
+    group.finish();
+}
+
+macro_rules! bench_days {
+    ($($module:ident :: $solution:ident),*) => {
+        fn bench_days(c: &mut Criterion) {
+            $(bench_day::<$module::$solution>(c);)*
+        }
+    };
+}
+
+bench_days!(
+    day1::Day1, day2::Day2, day3::Day3, day4::Day4, day5::Day5, day6::Day6, day7::Day7, day8::Day8, day9::Day9,
+    day10::Day10, day11::Day11, day12::Day12, day13::Day13, day14::Day14, day15::Day15, day16::Day16, day17::Day17,
+    day18::Day18, day19::Day19, day20::Day20, day21::Day21, day22::Day22, day23::Day23, day24::Day24, day25::Day25
+);
+
+criterion_group!(benches, bench_days);
+criterion_main!(benches);
@@ -1,28 +1,29 @@
-#![feature(array_windows)]
-
+use adventofcode2021::day1::sweep;
+use adventofcode2021::log;
 use adventofcode2021::prelude::*;
 
-fn count_increasing(lines: &[i64]) -> usize {
-    let count_increasing = lines.array_windows().filter(|[a, b]| a < b).count();
-    count_increasing
+/// `--window K` streams the input and compares all windows of K measurements instead of solving the puzzle
+fn try_sweep(window: &str, args: impl Iterator<Item = String>) -> Result<()> {
+    let window = usize::from_str(window).ok().filter(|window| *window > 0);
+    let window = window.ok_or_else(|| Error::General("--window needs a positive size".into()))?;
+    let source = Input::from_args(1, args)?;
+    println!("{}", sweep(source.reader()?, window).in_file(&source)?);
+    Ok(())
 }
 
-pub fn main() -> Result<()> {
-    let lines = read_lines("data/a1_input.txt")?;
-    let lines = lines
-        .into_iter()
-        .map(|l| Ok(i64::from_str(&l)?))
-        .collect::<Result<Vec<i64>>>()?;
-
-    println!("{}", count_increasing(&lines));
-
-    let sums = lines
-        .iter()
-        .zip(lines.iter().skip(1).zip(lines.iter().skip(2)))
-        .map(|(a, (b, c))| a + b + c)
-        .collect::<Vec<_>>();
-
-    println!("{}", count_increasing(&sums));
-
-    Ok(())
+pub fn main() {
+    let mut args = std::env::args().skip(1).collect::<Vec<_>>();
+    if let Some(level) = log::take_verbosity(&mut args) {
+        log::set_level(level);
+    }
+    match args.iter().position(|arg| arg == "--window") {
+        Some(i) => {
+            let window = args.drain(i..(i + 2).min(args.len())).nth(1).unwrap_or_default();
+            if let Err(e) = try_sweep(&window, args.into_iter()) {
+                eprintln!("Error: {}", e);
+                std::process::exit(1);
+            }
+        }
+        None => run_day::<day1::Day1>(),
+    }
 }
@@ -1,39 +1,42 @@
+use adventofcode2021::day2::{parse, Aim, Model, Plain, Submarine};
+use adventofcode2021::log;
 use adventofcode2021::prelude::*;
+use std::io::{BufWriter, Write};
+
+/// `--trajectory FILE` writes the course as CSV instead of solving the puzzle
+///
+/// The course follows the aim of the second part, `--plain` the commands as the first part reads them.
+fn try_export(file: &str, mut args: Vec<String>) -> Result<()> {
+    let plain = args.iter().any(|arg| arg == "--plain");
+    args.retain(|arg| arg != "--plain");
+    let model: &dyn Model = if plain { &Plain } else { &Aim };
+
+    let source = Input::from_args(2, args.into_iter())?;
+    let commands = parse(&source.read()?).in_file(&source)?;
+    let mut w = BufWriter::new(std::fs::File::create(file).context(file)?);
+    Submarine::new(model).write_trajectory(&mut w, &commands)?;
+    w.flush()?;
+    eprintln!("Wrote {}", file);
+    Ok(())
+}
 
-
-pub fn main() -> Result<()> {
-    let data = include_str!("../../data/a2_input.txt");
-
-    let (x, depth) = data.lines().try_fold((0, 0), |(mut x, mut depth), line| -> Result<_> {
-        if let Some(forward) = line.strip_prefix("forward ") {
-            x += forward.parse::<i32>()?;
-        } else if let Some(down) =  line.strip_prefix("down ") {
-            depth += down.parse::<i32>()?;
-        } else if let Some(up) =  line.strip_prefix("up ") {
-            depth -= up.parse::<i32>()?;
-        }
-        Ok((x, depth))
-    })?;
-
-    let part1 = x*depth;
-    println!("{}", part1);
-
-
-    let (_, x, depth) = data.lines().try_fold((0, 0, 0), |(mut aim, mut x, mut depth), line| -> Result<_> {
-        if let Some(forward) = line.strip_prefix("forward ") {
-            let n = forward.parse::<i32>()?;
-            x += n;
-            depth += aim*n;
-        } else if let Some(down) =  line.strip_prefix("down ") {
-            aim += down.parse::<i32>()?;
-        } else if let Some(up) =  line.strip_prefix("up ") {
-            aim -= up.parse::<i32>()?;
+pub fn main() {
+    let mut args = std::env::args().skip(1).collect::<Vec<_>>();
+    if let Some(level) = log::take_verbosity(&mut args) {
+        log::set_level(level);
+    }
+    match args.iter().position(|arg| arg == "--trajectory") {
+        Some(i) => {
+            let file = args.drain(i..(i + 2).min(args.len())).nth(1);
+            let result = match file {
+                Some(file) => try_export(&file, args),
+                None => Err(Error::General("Missing file for --trajectory".into())),
+            };
+            if let Err(e) = result {
+                eprintln!("Error: {}", e);
+                std::process::exit(1);
+            }
         }
-        Ok((aim, x, depth))
-    })?;
-
-    let part2 = x*depth;
-    println!("{}", part2);
-
-    Ok(())
+        None => run_day::<day2::Day2>(),
+    }
 }
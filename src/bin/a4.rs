@@ -1,74 +1,53 @@
+use adventofcode2021::day4::{parse, Goal};
+use adventofcode2021::log;
 use adventofcode2021::prelude::*;
 
-fn read_board<'a>(lines: impl Iterator<Item = &'a str>) -> Result<[[i32; 5]; 5]> {
-    let mut result = [[0; 5]; 5];
-    result.iter_mut().zip(lines).try_for_each(|(row, line)| {
-        row.iter_mut()
-            .zip(line.split_ascii_whitespace())
-            .try_for_each(|(r, n)| -> Result<()> {
-                *r = i32::from_str(n)?;
-                Ok(())
-            })
-    })?;
-    Ok(result)
-}
-
-pub fn main() -> Result<()> {
-    let data = include_str!("../../data/a4_input.txt");
-    let mut lines = data.lines().peekable();
-    let numbers = lines
-        .next()
-        .ok_or(Error::EmptyIterator)?
-        .split(",")
-        .map(|n| Ok(i32::from_str(n)?))
-        .collect::<Result<Vec<i32>>>()?;
-    let _empty_line = lines.next().ok_or(Error::EmptyIterator)?;
+/// `--report` prints every win in order instead of solving the puzzle, `--diagonals` lets diagonals win
+fn try_report(mut args: Vec<String>) -> Result<()> {
+    let diagonals = args.iter().any(|arg| arg == "--diagonals");
+    args.retain(|arg| arg != "--report" && arg != "--diagonals");
 
-    let mut boards = vec![];
-    while lines.peek().is_some() {
-        boards.push(read_board(&mut lines)?);
-        if let Some(line) = lines.peek() {
-            if line.is_empty() {
-                lines.next();
-            } else {
-                return Err(Error::General(format!("Unexpected non-empty line: {}", line)));
-            }
-        }
+    let source = Input::from_args(4, args.into_iter())?;
+    let (draws, bingo) = parse(&source.read()?).in_file(&source)?;
+    for win in bingo.diagonals(diagonals).play(&draws) {
+        println!("{}", win);
     }
+    Ok(())
+}
 
-    let mut markers = vec![[[false; 5]; 5]; boards.len()];
-
-    for n in numbers {
-        for (board, marker) in boards.iter_mut().zip(markers.iter_mut()) {
-            let mut marked = false;
-            for row in 0..5 {
-                for col in 0..5 {
-                    if board[row][col] == n {
-                        marker[row][col] = true;
-                        marked = true;
-                    }
-                }
-            }
-            if marked {
-                let horz_win = (0..5).any(|row| marker[row].iter().all(|col| *col));
-                let vert_win = (0..5).any(|col| marker.iter().all(|m| m[col as usize]));
-                if horz_win || vert_win {
-                    let sum_unmarked: i32 = board
-                        .iter()
-                        .zip(marker.iter())
-                        .flat_map(|(row, m)| row.iter().zip(m.iter()).filter(|(_, m)| !**m).map(|(x, _)| *x))
-                        .sum();
-                    let score = n * sum_unmarked;
-
-                    // answer for part1 is first printed score, part2 is the last printed score
-                    println!("Score = {}", score);
+/// `--rig BOARD` prints an input with the fewest draws under which that board wins first, `--last` makes it win last
+fn try_rig(board: &str, mut args: Vec<String>) -> Result<()> {
+    let diagonals = args.iter().any(|arg| arg == "--diagonals");
+    let goal = if args.iter().any(|arg| arg == "--last") { Goal::Last } else { Goal::First };
+    args.retain(|arg| arg != "--diagonals" && arg != "--last");
+
+    let source = Input::from_args(4, args.into_iter())?;
+    let (_, bingo) = parse(&source.read()?).in_file(&source)?;
+    let bingo = bingo.diagonals(diagonals);
+    let target = usize::from_str(board).map_err(|_| Error::General(format!("No board {}", board)))?;
+    let draws = bingo.rigged_draws(target, goal)?;
+    let draws = draws.iter().map(u32::to_string).collect::<Vec<_>>();
+    let boards = bingo.boards().iter().map(ToString::to_string).collect::<Vec<_>>();
+    print!("{}\n\n{}", draws.join(","), boards.join("\n"));
+    Ok(())
+}
 
-                    // invalidate board, removing the board would be nicer
-                    board.fill([-1; 5]);
-                }
-            }
-        }
+pub fn main() {
+    let mut args = std::env::args().skip(1).collect::<Vec<_>>();
+    if let Some(level) = log::take_verbosity(&mut args) {
+        log::set_level(level);
+    }
+    let result = if let Some(i) = args.iter().position(|arg| arg == "--rig") {
+        let board = args.drain(i..(i + 2).min(args.len())).nth(1).unwrap_or_default();
+        try_rig(&board, args)
+    } else if args.iter().any(|arg| arg == "--report") {
+        try_report(args)
+    } else {
+        run_day::<day4::Day4>();
+        Ok(())
+    };
+    if let Err(e) = result {
+        eprintln!("Error: {}", e);
+        std::process::exit(1);
     }
-
-    Ok(())
 }
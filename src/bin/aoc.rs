@@ -0,0 +1,214 @@
+use adventofcode2021::prelude::*;
+use adventofcode2021::log;
+use adventofcode2021::visualize::Visualizer;
+use std::ops::RangeInclusive;
+use std::path::PathBuf;
+
+const USAGE: &str = "Usage: aoc run [-v|-vv|-vvv] [--day <N|N-M|all>] [--part <1|2>] [--input <path|-> | --example [suffix]] \
+[--image <dir>]
+       aoc visualize [-v|-vv|-vvv] --day <N> [--input <path|-> | --example [suffix]] [--fps <N>] [--paused] [--jump <step>] \
+[--steps <N>] [--frames <dir>]";
+
+#[derive(PartialEq)]
+enum Command {
+    Run,
+    Visualize,
+}
+
+struct Options {
+    command: Command,
+    days: RangeInclusive<usize>,
+    part: Option<usize>,
+    input: Option<String>,
+    example: Option<String>,
+    image: Option<PathBuf>,
+    visualizer: Visualizer,
+    frames: Option<PathBuf>,
+}
+
+impl Options {
+    fn input(&self, day: usize) -> Input {
+        match (&self.input, &self.example) {
+            (Some(path), _) if path == "-" => Input::Stdin,
+            (Some(path), _) => Input::Path(path.into()),
+            (None, Some(suffix)) => Input::Example(day, suffix.clone()),
+            (None, None) => Input::Puzzle(day),
+        }
+    }
+}
+
+fn parse_days(days: &str) -> Result<RangeInclusive<usize>> {
+    let range = if days == "all" {
+        1..=25
+    } else if let Some((from, to)) = days.split_once('-') {
+        usize::from_str(from)?..=usize::from_str(to)?
+    } else {
+        let day = usize::from_str(days)?;
+        day..=day
+    };
+
+    if range.is_empty() || *range.start() < 1 || *range.end() > 25 {
+        return Err(Error::General(format!("Invalid days {}", days)));
+    }
+
+    Ok(range)
+}
+
+fn parse_args(args: impl Iterator<Item = String>) -> Result<Options> {
+    let mut args = args.peekable();
+    let command = match args.next().as_deref() {
+        Some("run") => Command::Run,
+        Some("visualize") => Command::Visualize,
+        Some(command) => return Err(Error::General(format!("Unknown command {}", command))),
+        None => return Err(Error::General("Missing command".into())),
+    };
+
+    let mut options = Options {
+        command,
+        days: 1..=25,
+        part: None,
+        input: None,
+        example: None,
+        image: None,
+        visualizer: Visualizer::new(),
+        frames: None,
+    };
+    let mut visualizer_options = false;
+
+    while let Some(arg) = args.next() {
+        if arg == "--example" {
+            let suffix = args.next_if(|suffix| !suffix.starts_with("--"));
+            options.example = Some(suffix.unwrap_or_default());
+            continue;
+        }
+        if arg == "--paused" {
+            options.visualizer = options.visualizer.paused(true);
+            visualizer_options = true;
+            continue;
+        }
+        let value = args
+            .next()
+            .ok_or_else(|| Error::General(format!("Missing value for {}", arg)))?;
+        match arg.as_str() {
+            "--day" => options.days = parse_days(&value)?,
+            "--part" => match value.as_str() {
+                "1" => options.part = Some(1),
+                "2" => options.part = Some(2),
+                _ => return Err(Error::General(format!("Invalid part {}", value))),
+            },
+            "--input" => options.input = Some(value),
+            "--image" => options.image = Some(value.into()),
+            "--fps" => match f64::from_str(&value) {
+                Ok(fps) if fps > 0.0 => options.visualizer = options.visualizer.fps(fps),
+                _ => return Err(Error::General(format!("Invalid frame rate {}", value))),
+            },
+            "--jump" => options.visualizer = options.visualizer.jump(usize::from_str(&value)?),
+            "--steps" => options.visualizer = options.visualizer.max_steps(usize::from_str(&value)?),
+            "--frames" => options.frames = Some(value.into()),
+            _ => return Err(Error::General(format!("Unknown argument {}", arg))),
+        }
+        visualizer_options |= ["--fps", "--jump", "--steps", "--frames"].contains(&arg.as_str());
+    }
+
+    match options.command {
+        Command::Run if visualizer_options => {
+            return Err(Error::General("--fps, --paused, --jump, --steps and --frames require visualize".into()))
+        }
+        Command::Visualize if options.days.start() != options.days.end() => {
+            return Err(Error::General("visualize requires a single --day".into()))
+        }
+        Command::Visualize if options.part.is_some() || options.image.is_some() => {
+            return Err(Error::General("--part and --image require run".into()))
+        }
+        _ => {}
+    }
+
+    if options.input.is_some() && options.days.start() != options.days.end() {
+        return Err(Error::General("--input requires a single --day".into()));
+    }
+    if options.input.is_some() && options.example.is_some() {
+        return Err(Error::General("--input and --example are mutually exclusive".into()));
+    }
+
+    Ok(options)
+}
+
+fn run(options: &Options) -> Result<()> {
+    let parts = match options.part {
+        Some(part) => part..=part,
+        None => 1..=2,
+    };
+
+    let single_day = options.days.start() == options.days.end();
+
+    for day in options.days.clone() {
+        let source = options.input(day);
+        if !single_day && matches!(source, Input::Example(..)) && !source.path().map_or(false, |path| path.exists()) {
+            eprintln!("Day {}: no example {}", day, source);
+            continue;
+        }
+        let input = source.read()?;
+
+        let solution = &DAYS[day - 1];
+        // the last day only has a single puzzle
+        for part in parts.clone().filter(|&part| part <= solution.parts) {
+            let answer = (solution.solve_in)(&input, part, &source)?;
+            if answer.contains('\n') {
+                println!("Day {} Part {}:\n{}", day, part, answer);
+            } else {
+                println!("Day {} Part {}: {}", day, part, answer);
+            }
+            if let Some(dir) = &options.image {
+                if let Some(image) = (solution.render)(&input, part)? {
+                    save_image(dir, day, part, &image)?;
+                }
+            }
+        }
+    }
+
+    Ok(())
+}
+
+fn visualize(options: &Options) -> Result<()> {
+    let day = *options.days.start();
+    let source = options.input(day);
+    let mut simulation = (DAYS[day - 1].simulate)(&source.read()?)
+        .in_file(&source)?
+        .ok_or_else(|| Error::General(format!("Day {} has no visualization", day)))?;
+
+    let step = match &options.frames {
+        Some(dir) => {
+            let step = options.visualizer.write_frames(simulation.as_mut(), dir)?;
+            eprintln!("Wrote frames up to step {} into {}", step, dir.display());
+            step
+        }
+        None => options.visualizer.run(simulation.as_mut())?,
+    };
+    println!("Day {}: stopped at step {}", day, step);
+
+    Ok(())
+}
+
+pub fn main() {
+    let mut args = std::env::args().skip(1).collect::<Vec<_>>();
+    if let Some(level) = log::take_verbosity(&mut args) {
+        log::set_level(level);
+    }
+
+    let options = match parse_args(args.into_iter()) {
+        Ok(options) => options,
+        Err(e) => {
+            eprintln!("{}\n{}", e, USAGE);
+            std::process::exit(2);
+        }
+    };
+
+    let result = match options.command {
+        Command::Run => run(&options),
+        Command::Visualize => visualize(&options),
+    };
+    if let Err(e) = result {
+        eprintln!("Error: {}", e);
+        std::process::exit(1);
+    }
+}
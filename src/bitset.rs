@@ -0,0 +1,315 @@
+use std::fmt::{Debug, Formatter};
+use std::hash::{Hash, Hasher};
+
+/// A fixed size set of `64 * N` small integers, with the same API as [`Bitmap64`](crate::Bitmap64)
+#[derive(Clone, Copy, Eq, PartialEq, Ord, PartialOrd, Hash)]
+pub struct Bitmap<const N: usize>([u64; N]);
+
+bit_ops!(Bitmap<N>, const N: usize);
+
+impl<const N: usize> Default for Bitmap<N> {
+    #[inline]
+    fn default() -> Self {
+        Bitmap([0; N])
+    }
+}
+
+impl<const N: usize> Bitmap<N> {
+    pub const CAPACITY: usize = 64 * N;
+
+    #[inline]
+    pub fn is_empty(&self) -> bool {
+        self.0.iter().all(|word| *word == 0)
+    }
+
+    #[inline]
+    pub fn len(&self) -> usize {
+        self.0.iter().map(|word| word.count_ones() as usize).sum()
+    }
+
+    #[inline]
+    pub fn is_set(&self, value: usize) -> bool {
+        self.0[value / 64] & (1 << (value % 64)) != 0
+    }
+
+    #[inline]
+    pub fn set_mut(&mut self, value: usize) {
+        self.0[value / 64] |= 1 << (value % 64);
+    }
+
+    #[inline]
+    pub fn unset_mut(&mut self, value: usize) {
+        self.0[value / 64] &= !(1 << (value % 64));
+    }
+
+    #[inline]
+    pub fn set(&self, value: usize) -> Self {
+        let mut result = *self;
+        result.set_mut(value);
+        result
+    }
+
+    #[inline]
+    pub fn unset(&self, value: usize) -> Self {
+        let mut result = *self;
+        result.unset_mut(value);
+        result
+    }
+
+    #[inline]
+    pub fn words(&self) -> &[u64; N] {
+        &self.0
+    }
+
+    #[inline]
+    fn zip_with(&self, other: &Self, f: impl Fn(u64, u64) -> u64) -> Self {
+        let mut result = *self;
+        result.0.iter_mut().zip(other.0.iter()).for_each(|(a, b)| *a = f(*a, *b));
+        result
+    }
+
+    #[inline]
+    pub fn and(&self, other: &Self) -> Self {
+        self.zip_with(other, |a, b| a & b)
+    }
+
+    #[inline]
+    pub fn and_not(&self, other: &Self) -> Self {
+        self.zip_with(other, |a, b| a & !b)
+    }
+
+    #[inline]
+    pub fn or(&self, other: &Self) -> Self {
+        self.zip_with(other, |a, b| a | b)
+    }
+
+    #[inline]
+    pub fn is_subset(&self, other: &Self) -> bool {
+        self.0.iter().zip(other.0.iter()).all(|(a, b)| a & !b == 0)
+    }
+
+    #[inline]
+    pub fn iter(&self) -> BitIter<std::array::IntoIter<u64, N>> {
+        BitIter::new(self.0.into_iter())
+    }
+}
+
+impl<const N: usize> Debug for Bitmap<N> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        f.debug_set().entries(self.iter()).finish()
+    }
+}
+
+impl<const N: usize> From<[u64; N]> for Bitmap<N> {
+    #[inline]
+    fn from(words: [u64; N]) -> Self {
+        Bitmap(words)
+    }
+}
+
+impl<const N: usize> FromIterator<usize> for Bitmap<N> {
+    fn from_iter<T: IntoIterator<Item = usize>>(iter: T) -> Self {
+        let mut bitmap = Bitmap::default();
+        for elem in iter {
+            assert!(elem < Self::CAPACITY);
+            bitmap.set_mut(elem);
+        }
+        bitmap
+    }
+}
+
+impl<const N: usize> IntoIterator for Bitmap<N> {
+    type Item = usize;
+    type IntoIter = BitIter<std::array::IntoIter<u64, N>>;
+
+    #[inline]
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
+    }
+}
+
+/// A set of small integers which grows as needed, with the same API as [`Bitmap64`](crate::Bitmap64)
+#[derive(Clone, Default)]
+pub struct BitSet(Vec<u64>);
+
+impl BitSet {
+    pub fn with_capacity(bits: usize) -> Self {
+        BitSet(vec![0; (bits + 63) / 64])
+    }
+
+    /// The words up to the last non-zero one, so that sets only differing in capacity compare equal
+    #[inline]
+    pub fn words(&self) -> &[u64] {
+        let len = self.0.iter().rposition(|word| *word != 0).map_or(0, |i| i + 1);
+        &self.0[..len]
+    }
+
+    #[inline]
+    pub fn is_empty(&self) -> bool {
+        self.0.iter().all(|word| *word == 0)
+    }
+
+    #[inline]
+    pub fn len(&self) -> usize {
+        self.0.iter().map(|word| word.count_ones() as usize).sum()
+    }
+
+    #[inline]
+    pub fn is_set(&self, value: usize) -> bool {
+        self.0.get(value / 64).map_or(false, |word| word & (1 << (value % 64)) != 0)
+    }
+
+    #[inline]
+    pub fn set_mut(&mut self, value: usize) {
+        if value / 64 >= self.0.len() {
+            self.0.resize(value / 64 + 1, 0);
+        }
+        self.0[value / 64] |= 1 << (value % 64);
+    }
+
+    #[inline]
+    pub fn unset_mut(&mut self, value: usize) {
+        if let Some(word) = self.0.get_mut(value / 64) {
+            *word &= !(1 << (value % 64));
+        }
+    }
+
+    #[inline]
+    pub fn set(&self, value: usize) -> Self {
+        let mut result = self.clone();
+        result.set_mut(value);
+        result
+    }
+
+    #[inline]
+    pub fn unset(&self, value: usize) -> Self {
+        let mut result = self.clone();
+        result.unset_mut(value);
+        result
+    }
+
+    pub fn and(&self, other: &BitSet) -> Self {
+        BitSet(self.0.iter().zip(other.0.iter()).map(|(a, b)| a & b).collect())
+    }
+
+    pub fn and_not(&self, other: &BitSet) -> Self {
+        let mut result = self.clone();
+        result.0.iter_mut().zip(other.0.iter()).for_each(|(a, b)| *a &= !b);
+        result
+    }
+
+    pub fn or(&self, other: &BitSet) -> Self {
+        let (mut result, shorter) = if self.0.len() >= other.0.len() {
+            (self.clone(), other)
+        } else {
+            (other.clone(), self)
+        };
+        result.0.iter_mut().zip(shorter.0.iter()).for_each(|(a, b)| *a |= b);
+        result
+    }
+
+    /// Whether all values of this set are in `other`, without allocating
+    pub fn is_subset(&self, other: &BitSet) -> bool {
+        self.0.iter().enumerate().all(|(i, a)| a & !other.0.get(i).copied().unwrap_or(0) == 0)
+    }
+
+    #[inline]
+    pub fn iter(&self) -> BitIter<std::iter::Copied<std::slice::Iter<u64>>> {
+        BitIter::new(self.0.iter().copied())
+    }
+}
+
+impl PartialEq for BitSet {
+    fn eq(&self, other: &Self) -> bool {
+        self.words() == other.words()
+    }
+}
+
+impl Eq for BitSet {}
+
+impl Hash for BitSet {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.words().hash(state)
+    }
+}
+
+impl Debug for BitSet {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        f.debug_set().entries(self.iter()).finish()
+    }
+}
+
+impl FromIterator<usize> for BitSet {
+    fn from_iter<T: IntoIterator<Item = usize>>(iter: T) -> Self {
+        let mut bitset = BitSet::default();
+        for elem in iter {
+            bitset.set_mut(elem);
+        }
+        bitset
+    }
+}
+
+impl<'a> IntoIterator for &'a BitSet {
+    type Item = usize;
+    type IntoIter = BitIter<std::iter::Copied<std::slice::Iter<'a, u64>>>;
+
+    #[inline]
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
+    }
+}
+
+/// Iterates the set bits of a sequence of 64 bit words in ascending order
+pub struct BitIter<I> {
+    words: I,
+    current: u64,
+    offset: usize,
+}
+
+impl<I: Iterator<Item = u64>> BitIter<I> {
+    fn new(mut words: I) -> Self {
+        let current = words.next().unwrap_or(0);
+        BitIter { words, current, offset: 0 }
+    }
+}
+
+impl<I: Iterator<Item = u64>> Iterator for BitIter<I> {
+    type Item = usize;
+
+    #[inline]
+    fn next(&mut self) -> Option<Self::Item> {
+        while self.current == 0 {
+            self.current = self.words.next()?;
+            self.offset += 64;
+        }
+        let tz = self.current.trailing_zeros();
+        self.current &= !(1 << tz);
+        Some(self.offset + tz as usize)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_bitmap() {
+        let bitmap = vec![0, 1, 63, 64, 130].into_iter().collect::<Bitmap<3>>();
+        assert_eq!(bitmap.len(), 5);
+        assert!(bitmap.is_set(64) && !bitmap.is_set(65));
+        assert_eq!(bitmap.unset(63).iter().collect::<Vec<_>>(), vec![0, 1, 64, 130]);
+        assert_eq!(bitmap.and_not(&Bitmap::default().set(1)).and(&Bitmap::default().set(0).set(130)).len(), 2);
+        assert!(bitmap.unset(1).is_subset(&bitmap) && !bitmap.is_subset(&bitmap.unset(1)));
+    }
+
+    #[test]
+    fn test_bitset() {
+        let bitset = vec![3, 200].into_iter().collect::<BitSet>();
+        assert_eq!(bitset.iter().collect::<Vec<_>>(), vec![3, 200]);
+        assert!(!bitset.is_set(1000));
+        assert_eq!(bitset.unset(200), BitSet::default().set(3));
+        assert_eq!(bitset.or(&BitSet::default().set(64)).len(), 3);
+        assert_eq!(bitset.and(&BitSet::default().set(3)), BitSet::default().set(3));
+        assert!(BitSet::default().set(3).is_subset(&bitset) && !bitset.is_subset(&BitSet::default().set(3)));
+    }
+}
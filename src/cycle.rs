@@ -0,0 +1,109 @@
+/// Where the states of an iterated step function start repeating
+///
+/// The state after `start` steps is the first one which comes back, it does so every `period` steps.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Cycle<S> {
+    pub start: usize,
+    pub period: usize,
+    /// The state after `start` steps
+    pub state: S,
+}
+
+impl<S> Cycle<S> {
+    /// Whether the states stop changing, stepping the repeated state returns it unchanged
+    pub fn is_fixed_point(&self) -> bool {
+        self.period == 1
+    }
+
+    /// The number of steps before `steps` which lead to the same state, at most `start + period - 1`
+    pub fn reduce(&self, steps: usize) -> usize {
+        if steps < self.start {
+            steps
+        } else {
+            self.start + (steps - self.start) % self.period
+        }
+    }
+}
+
+/// Steps from `initial` until a state repeats, with Brent's algorithm
+///
+/// Only two states are kept at a time, but the step function is called about three times per step until the cycle.
+/// Loops forever if the states never repeat, see [`find_cycle_within`].
+pub fn find_cycle<S: Clone + Eq>(initial: S, step: impl FnMut(&S) -> S) -> Cycle<S> {
+    find_cycle_within(initial, step, usize::MAX).expect("a cycle within usize::MAX steps")
+}
+
+/// Like [`find_cycle`], but gives up with `None` when no state repeated after `limit` steps
+pub fn find_cycle_within<S: Clone + Eq>(initial: S, mut step: impl FnMut(&S) -> S, limit: usize) -> Option<Cycle<S>> {
+    // find the period: the hare runs ahead, the tortoise teleports to it at every power of two
+    let (mut power, mut period) = (1, 1);
+    let mut tortoise = initial.clone();
+    let mut hare = step(&initial);
+    let mut steps = 1;
+    while tortoise != hare {
+        if steps >= limit {
+            return None;
+        }
+        if power == period {
+            tortoise = hare.clone();
+            power *= 2;
+            period = 0;
+        }
+        hare = step(&hare);
+        period += 1;
+        steps += 1;
+    }
+
+    // find the start: with the hare `period` steps ahead, both meet at the first repeated state
+    let mut tortoise = initial.clone();
+    let mut hare = initial;
+    for _ in 0..period {
+        hare = step(&hare);
+    }
+    let mut start = 0;
+    while tortoise != hare {
+        tortoise = step(&tortoise);
+        hare = step(&hare);
+        start += 1;
+    }
+
+    Some(Cycle {
+        start,
+        period,
+        state: tortoise,
+    })
+}
+
+/// The state after `steps` steps, skipping all full rounds of a cycle
+///
+/// States which never repeat within `steps` steps are simply stepped through, at about twice the cost.
+pub fn state_after<S: Clone + Eq>(initial: S, mut step: impl FnMut(&S) -> S, steps: usize) -> S {
+    let (remaining, mut state) = match find_cycle_within(initial.clone(), &mut step, steps) {
+        Some(cycle) if steps >= cycle.start => (cycle.reduce(steps) - cycle.start, cycle.state),
+        _ => (steps, initial),
+    };
+    for _ in 0..remaining {
+        state = step(&state);
+    }
+    state
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_find_cycle() {
+        // 3 -> 10 -> 5 -> 16 -> 8 -> 4 -> 2 -> 1 -> 4
+        let collatz = |n: &u64| if n % 2 == 0 { n / 2 } else { 3 * n + 1 };
+        let cycle = find_cycle(3, collatz);
+        assert_eq!(cycle, Cycle { start: 5, period: 3, state: 4 });
+        assert_eq!((cycle.reduce(4), cycle.reduce(1_000_000_000)), (4, 7));
+        assert_eq!(state_after(3, collatz, 1_000_000_000), 1);
+        assert_eq!(state_after(3, collatz, 3), 16);
+
+        assert!(find_cycle(7, |n: &u64| n / 2).is_fixed_point());
+        assert_eq!(find_cycle_within(0, |n: &u64| n + 1, 100), None);
+        assert_eq!(state_after(0, |n: &u64| n + 1, 100), 100);
+    }
+}
@@ -0,0 +1,118 @@
+use crate::parse::Block;
+use crate::prelude::*;
+use std::collections::VecDeque;
+use std::fmt::{Display, Formatter};
+use std::io::BufRead;
+
+/// Parses one depth measurement per line
+pub fn parse(input: &str) -> Result<Vec<i64>> {
+    Block::new(input).map_lines(|line| Ok(i64::from_str(line)?))
+}
+
+/// How the sum of each window of measurements compares to the window before
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct SweepReport {
+    pub increased: usize,
+    pub decreased: usize,
+    pub unchanged: usize,
+}
+
+impl Display for SweepReport {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{} increased, {} decreased, {} unchanged", self.increased, self.decreased, self.unchanged)
+    }
+}
+
+/// Compares sliding windows of measurements one measurement at a time, keeping only the last window in memory
+///
+/// Consecutive windows share all but their first and last measurement,
+/// so comparing their sums comes down to comparing the measurement entering with the one leaving.
+#[derive(Debug, Clone)]
+pub struct Sweep {
+    size: usize,
+    window: VecDeque<i64>,
+    report: SweepReport,
+}
+
+impl Sweep {
+    pub fn new(size: usize) -> Self {
+        assert!(size > 0, "sweep with an empty window");
+        Sweep {
+            size,
+            window: VecDeque::with_capacity(size),
+            report: SweepReport::default(),
+        }
+    }
+
+    pub fn push(&mut self, depth: i64) {
+        if self.window.len() == self.size {
+            let leaving = self.window.pop_front().unwrap();
+            match depth.cmp(&leaving) {
+                std::cmp::Ordering::Greater => self.report.increased += 1,
+                std::cmp::Ordering::Less => self.report.decreased += 1,
+                std::cmp::Ordering::Equal => self.report.unchanged += 1,
+            }
+        }
+        self.window.push_back(depth);
+    }
+
+    pub fn report(&self) -> SweepReport {
+        self.report
+    }
+}
+
+/// Compares the windows of `size` measurements of a slice or iterator
+pub fn compare_windows(depths: impl IntoIterator<Item = i64>, size: usize) -> SweepReport {
+    let mut sweep = Sweep::new(size);
+    depths.into_iter().for_each(|depth| sweep.push(depth));
+    sweep.report()
+}
+
+/// Compares the windows of `size` measurements read line by line, without loading the input into memory
+pub fn sweep(reader: impl BufRead, size: usize) -> Result<SweepReport> {
+    let mut sweep = Sweep::new(size);
+    for (i, line) in reader.lines().enumerate() {
+        let line = line?;
+        sweep.push(i64::from_str(line.trim()).at_line(i + 1, &line)?);
+    }
+    Ok(sweep.report())
+}
+
+pub struct Day1;
+
+impl Solution for Day1 {
+    const DAY: usize = 1;
+
+    type Input = Vec<i64>;
+    type Part1 = usize;
+    type Part2 = usize;
+
+    fn parse(input: &str) -> Result<Self::Input> {
+        parse(input)
+    }
+
+    fn part1(depths: &Self::Input) -> Result<usize> {
+        Ok(compare_windows(depths.iter().copied(), 1).increased)
+    }
+
+    fn part2(depths: &Self::Input) -> Result<usize> {
+        Ok(compare_windows(depths.iter().copied(), 3).increased)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_sweep() {
+        let input = "199\n200\n208\n210\n200\n207\n240\n269\n260\n263\n";
+        let report = |increased, decreased, unchanged| SweepReport { increased, decreased, unchanged };
+        assert_eq!(sweep(input.as_bytes(), 1).unwrap(), report(7, 2, 0));
+        assert_eq!(sweep(input.as_bytes(), 3).unwrap(), report(5, 1, 1));
+        assert_eq!(sweep(input.as_bytes(), 10).unwrap(), report(0, 0, 0));
+        let error = sweep("1\nx\n".as_bytes(), 1).unwrap_err();
+        assert_eq!(error.to_string(), "line 2: Parse: invalid digit found in string in \"x\"");
+        assert_eq!(parse("1\nx\n").unwrap_err().to_string(), error.to_string());
+    }
+}
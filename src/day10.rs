@@ -0,0 +1,188 @@
+use std::fmt::{Display, Formatter, Write};
+use crate::parse::Block;
+use crate::prelude::*;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SyntaxError {
+    /// A closing delimiter which does not match the open chunk
+    UnexpectedChar(u8),
+    /// The line ended while the chunk with this closing delimiter was still open
+    Incomplete(u8),
+}
+
+/// A chunk with its delimiters and the chunks it contains
+pub struct Chunk {
+    pub start_char: u8,
+    pub end_char: u8,
+    pub children: Vec<Chunk>,
+}
+
+impl Chunk {
+    fn new(start_char: u8, end_char: u8) -> Self {
+        Self {
+            start_char,
+            end_char,
+            children: vec![]
+        }
+    }
+}
+
+impl Display for Chunk {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        f.write_char(self.start_char as char)?;
+        for chunk in self.children.iter() {
+            chunk.fmt(f)?;
+        }
+        f.write_char(self.end_char as char)?;
+        Ok(())
+    }
+}
+
+fn closing_delimiter(ch: u8) -> Option<u8> {
+    match ch {
+        b'(' => Some(b')'),
+        b'[' => Some(b']'),
+        b'{' => Some(b'}'),
+        b'<' => Some(b'>'),
+        _ => None,
+    }
+}
+
+fn is_closing_delimiter(ch: u8) -> bool {
+    match ch {
+        b')' | b']' | b'}' | b'>' => true,
+        _ => false,
+    }
+}
+
+/// Syntax error score of an unexpected closing delimiter
+pub fn score(ch: u8) -> u64 {
+    match ch {
+        b')' => 3,
+        b']' => 57,
+        b'}' => 1197,
+        b'>' => 25137,
+        _ => unreachable!("invalid character for score: {}", ch as char)
+    }
+}
+
+/// Autocomplete score of the closing delimiters which complete a line
+pub fn score_part2(chars: &[u8]) -> u64 {
+    chars.iter().fold(0_u64, |a, ch| {
+        a * 5 + match *ch {
+            b')' => 1,
+            b']' => 2,
+            b'}' => 3,
+            b'>' => 4,
+            _ => unreachable!("invalid character for score: {}", *ch as char)
+        }
+    })
+}
+
+fn parse_chunk(start_char: u8, mut line: &[u8], recover: bool) -> std::result::Result<(&[u8], Chunk), SyntaxError> {
+    let end_char = closing_delimiter(start_char).ok_or(SyntaxError::UnexpectedChar(start_char))?;
+    if line.is_empty() {
+        if recover {
+            return Ok((line, Chunk::new(start_char, end_char)));
+        } else {
+            return Err(SyntaxError::Incomplete(end_char))
+        }
+    }
+    let mut chunk = Chunk::new(start_char, end_char);
+    while let Some((first, rest)) = line.split_first() {
+        if *first == end_char {
+            line = rest;
+            break;
+        } else if is_closing_delimiter(*first) {
+            return Err(SyntaxError::UnexpectedChar(*first))
+        } else {
+            let (rest, nested_chunk) = parse_chunk(*first, rest, recover)?;
+            line = rest;
+            chunk.children.push(nested_chunk);
+        }
+    }
+    Ok((line, chunk))
+}
+
+/// Parses the chunks of a line, `recover` closes the chunks of incomplete lines instead of failing
+pub fn parse_line(line: &[u8], recover: bool) -> std::result::Result<Vec<Chunk>, SyntaxError> {
+    let mut chunks = vec![];
+    let mut line = line;
+    while let Some((first, rest)) = line.split_first() {
+        let (rest, chunk) = parse_chunk(*first, rest, recover)?;
+        line = rest;
+        chunks.push(chunk);
+    }
+    Ok(chunks)
+}
+
+pub struct Day10;
+
+impl Solution for Day10 {
+    const DAY: usize = 10;
+
+    type Input = Vec<String>;
+    type Part1 = u64;
+    type Part2 = u64;
+
+    fn parse(input: &str) -> Result<Self::Input> {
+        Block::new(input).map_lines(|line| {
+            let is_delimiter = |ch: u8| closing_delimiter(ch).is_some() || is_closing_delimiter(ch);
+            if let Some(i) = line.bytes().position(|ch| !is_delimiter(ch)) {
+                return Err(Error::General(format!("Unexpected character {}", &line[i..i + 1]))).at_column(i + 1);
+            }
+            Ok(line.to_string())
+        })
+    }
+
+    fn part1(lines: &Self::Input) -> Result<u64> {
+        let part1 = lines.iter().map(|line| {
+            match parse_line(line.as_bytes(), false) {
+                Ok(_chunks) => {
+                    debug!("{}: Ok", line);
+                    0_u64
+                }
+                Err(SyntaxError::Incomplete(ch)) => {
+                    debug!("{}: Missing {}", line, ch as char);
+                    0_u64
+                }
+                Err(SyntaxError::UnexpectedChar(ch)) => {
+                    debug!("{}: Unexpected {}", line, ch as char);
+                    score(ch)
+                }
+            }
+        }).sum::<u64>();
+
+        Ok(part1)
+    }
+
+    fn part2(lines: &Self::Input) -> Result<u64> {
+        let mut part2_scores = lines.iter().filter_map(|line| {
+            match parse_line(line.as_bytes(), true) {
+                Ok(chunks) => {
+                    let formatted = chunks.iter().map(|chunk| chunk.to_string()).collect::<String>();
+                    if formatted.len() == line.len() {
+                        debug!("{}: Ok", line);
+                        None
+                    } else {
+                        debug!("Recovered {} to {}", line, &formatted);
+                        Some(formatted[line.len()..].to_string())
+                    }
+                },
+                Err(SyntaxError::Incomplete(ch)) => panic!("Missing {} (SHOULDN'T HAPPEN IN RECOVERY MODE)", ch as char),
+                Err(SyntaxError::UnexpectedChar(_)) => None,
+            }
+        }).map(|missing_chars| {
+            score_part2(missing_chars.as_bytes())
+        }).collect::<Vec<u64>>();
+
+        if part2_scores.is_empty() {
+            return Err(Error::EmptyIterator);
+        }
+
+        let mid = part2_scores.len() / 2;
+        let (_, part2, _) = part2_scores.select_nth_unstable(mid);
+
+        Ok(*part2)
+    }
+}
@@ -0,0 +1,106 @@
+use crate::cycle::find_cycle;
+use crate::prelude::*;
+use crate::grid::NEIGHBOURS8;
+
+/// Advances the simulation by one step and returns the number of flashes
+pub fn step(map: &mut Grid<u8>) -> usize {
+    let mut total_flashes = 0_usize;
+
+    map.cells_mut().iter_mut().for_each(|level| *level += 1);
+    let mut flashed = Grid::new(map.width(), map.height(), false);
+    loop {
+        let mut any_flashed = false;
+
+        for position in map.positions() {
+            if map[position] >= 10 && !flashed[position] {
+                flashed[position] = true;
+                any_flashed = true;
+                total_flashes += 1;
+
+                for delta in NEIGHBOURS8 {
+                    if let Some(neighbour) = map.offset(position, delta) {
+                        map[neighbour] = (map[neighbour] + 1).min(10);
+                    }
+                }
+            }
+        }
+
+        if !any_flashed {
+            break;
+        }
+    }
+
+    map.cells_mut().iter_mut().for_each(|level| {
+        if *level >= 10 {
+            *level = 0
+        }
+    });
+
+    total_flashes
+}
+
+/// The octopuses step by step, the ones which just flashed are drawn as `*`
+struct Octopuses {
+    map: Grid<u8>,
+    flashes: usize,
+}
+
+impl Simulation for Octopuses {
+    fn step(&mut self) -> bool {
+        self.flashes += step(&mut self.map);
+        true
+    }
+
+    fn render(&self) -> String {
+        let map = self.map.map(|level| if *level == 0 { '*' } else { (level + b'0') as char });
+        format!("{}\n{} flashes", map, self.flashes)
+    }
+
+    fn image(&self) -> Option<Image> {
+        Some(Image::Greymap(self.map.map(|level| if *level == 0 { 255 } else { level * 20 })))
+    }
+}
+
+pub struct Day11;
+
+impl Solution for Day11 {
+    const DAY: usize = 11;
+
+    type Input = Grid<u8>;
+    type Part1 = usize;
+    type Part2 = usize;
+
+    fn parse(input: &str) -> Result<Self::Input> {
+        Grid::parse_digits(input)
+    }
+
+    fn part1(map: &Self::Input) -> Result<usize> {
+        let mut map = map.clone();
+
+        let total_flashes = (0..100).map(|_| step(&mut map)).sum();
+
+        Ok(total_flashes)
+    }
+
+    fn part2(map: &Self::Input) -> Result<usize> {
+        let cycle = find_cycle(map.clone(), |map| {
+            let mut map = map.clone();
+            step(&mut map);
+            map
+        });
+
+        // all states come back within the first `start + period` steps, a synchronized flash has to be among them
+        let mut map = map.clone();
+        for step_count in 1..=cycle.start + cycle.period {
+            if step(&mut map) == map.len() {
+                return Ok(step_count);
+            }
+        }
+
+        Err(Error::General("The octopuses never flash simultaneously".into()))
+    }
+
+    fn simulation(map: &Self::Input) -> Option<Box<dyn Simulation>> {
+        Some(Box::new(Octopuses { map: map.clone(), flashes: 0 }))
+    }
+}
@@ -0,0 +1,97 @@
+use crate::graph::Graph;
+use crate::memo::Memo;
+use crate::prelude::*;
+
+pub struct Caves {
+    /// The attribute of a cave tells whether it is small
+    graph: Graph<bool>,
+    start: usize,
+    end: usize,
+}
+
+fn count_paths(caves: &Graph<bool>, current: usize, end: usize, visited: &BitSet, found: &mut usize) {
+    if current == end {
+        *found += 1
+    } else {
+        let visited = visited.set(current);
+        for to in caves.neighbours(current).iter().copied() {
+            let is_small = *caves.attribute(to);
+            let is_visited = visited.is_set(to);
+            if !is_small || !is_visited {
+                count_paths(caves, to, end, &visited, found);
+            }
+        }
+    }
+}
+
+/// The cave, the small caves visited before and whether a small cave was visited twice
+type PathState = (usize, BitSet, bool);
+
+fn count_paths_part2(caves: &Graph<bool>, memo: &mut Memo<PathState, usize>, current: usize, start: usize, end: usize, visited: &BitSet, visited_small_twice: bool) -> usize {
+    memo.get_or_insert_with((current, visited.clone(), visited_small_twice), |memo| {
+        if current == end {
+            return 1;
+        }
+
+        let is_small = *caves.attribute(current);
+        let visited_small_twice = visited_small_twice || (is_small && visited.is_set(current));
+        // big caves can be visited any number of times, leaving them out lets equivalent paths share a memo entry
+        let visited = if is_small { visited.set(current) } else { visited.clone() };
+        let mut found = 0;
+        for to in caves.neighbours(current).iter().copied() {
+            let is_small = *caves.attribute(to);
+            let is_visited = visited.is_set(to);
+            if !is_visited || !is_small || !(visited_small_twice || to == start) {
+                found += count_paths_part2(caves, memo, to, start, end, &visited, visited_small_twice);
+            }
+        }
+        found
+    })
+}
+
+/// Parses the cave connections, one `start-end` pair per line
+pub fn parse(input: &str) -> Result<Caves> {
+    let mut caves = Graph::<bool>::new();
+    for (i, line) in input.lines().enumerate() {
+        let (from, to) = line.split_once('-').ok_or(Error::PatternMatch).at_line(i + 1, line)?;
+        let (from, to) = caves.add_undirected_edge(from, to);
+        for id in [from, to] {
+            *caves.attribute_mut(id) = caves.name(id).starts_with(|ch: char| ch.is_ascii_lowercase());
+        }
+    }
+
+    let start = caves.id("start").ok_or_else(|| Error::General("Could not find start id".into()))?;
+    let end = caves.id("end").ok_or_else(|| Error::General("Could not find end id".into()))?;
+
+    Ok(Caves { graph: caves, start, end })
+}
+
+pub struct Day12;
+
+impl Solution for Day12 {
+    const DAY: usize = 12;
+
+    type Input = Caves;
+    type Part1 = usize;
+    type Part2 = usize;
+
+    fn parse(input: &str) -> Result<Self::Input> {
+        parse(input)
+    }
+
+    fn part1(caves: &Self::Input) -> Result<usize> {
+        let mut part1 = 0_usize;
+        count_paths(&caves.graph, caves.start, caves.end, &BitSet::default(), &mut part1);
+
+        Ok(part1)
+    }
+
+    fn part2(caves: &Self::Input) -> Result<usize> {
+        let mut memo = Memo::new();
+        let (start, end) = (caves.start, caves.end);
+        let paths = count_paths_part2(&caves.graph, &mut memo, start, start, end, &BitSet::default(), false);
+        info!("Memo: {}", memo);
+
+        Ok(paths)
+    }
+}
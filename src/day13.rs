@@ -0,0 +1,113 @@
+use crate::parse::Block;
+use crate::prelude::*;
+use crate::sparse::Position;
+
+#[derive(Debug, Clone, Copy, Eq, PartialEq)]
+pub enum Split {
+    X(i64),
+    Y(i64),
+}
+
+type Point = Point2<i64>;
+
+fn parse_split(line: &str) -> Result<Split> {
+    let (at, d) = line.split_once('=').ok_or(Error::PatternMatch)?;
+    let d = d.parse::<i64>().at_column(at.len() + 2)?;
+    if at.ends_with('x') {
+        Ok(Split::X(d))
+    } else if at.ends_with('y') {
+        Ok(Split::Y(d))
+    } else {
+        Err(Error::General(format!("Could not split: {}", line))).at_column(1)
+    }
+}
+
+/// Parses the dots, an empty line and the fold instructions
+pub fn parse(input: &str) -> Result<(Vec<Point>, Vec<Split>)> {
+    let (points, splits) = Block::new(input).header_body()?;
+    let points = points.map_lines(Point::from_str)?;
+    let splits = splits.map_lines(parse_split)?;
+
+    if points.is_empty() || splits.is_empty() {
+        return Err(Error::EmptyIterator);
+    }
+
+    Ok((points, splits))
+}
+
+/// Folds the points along a split line, merging the ones which end up on top of each other
+pub fn fold(points: &mut Vec<Point>, split: Split) {
+    match split {
+        Split::X(d) => {
+            points.iter_mut().filter(|p| p.x > d).for_each(|p| p.x = 2 * d - p.x);
+        }
+        Split::Y(d) => {
+            points.iter_mut().filter(|p| p.y > d).for_each(|p| p.y = 2 * d - p.y);
+        }
+    }
+    points.sort();
+    points.dedup();
+}
+
+/// Folds the paper along all splits, returning the dots that form the code
+pub fn code(points: &[Point], splits: &[Split]) -> Result<Grid<bool>> {
+    let mut points = points.to_vec();
+
+    for split in splits.iter().copied() {
+        fold(&mut points, split);
+    }
+
+    let mut code = SparseGrid::new(false);
+    for p in points.into_iter() {
+        code.set(Position::new(p.x as isize, p.y as isize), true);
+    }
+
+    if code.is_empty() {
+        return Err(Error::EmptyIterator);
+    }
+
+    Ok(code.to_grid())
+}
+
+pub struct Day13;
+
+impl Solution for Day13 {
+    const DAY: usize = 13;
+
+    type Input = (Vec<Point>, Vec<Split>);
+    type Part1 = usize;
+    type Part2 = String;
+
+    fn parse(input: &str) -> Result<Self::Input> {
+        parse(input)
+    }
+
+    fn part1((points, splits): &Self::Input) -> Result<usize> {
+        let mut points = points.clone();
+        let split = *splits.first().ok_or(Error::EmptyIterator)?;
+
+        fold(&mut points, split);
+
+        Ok(points.len())
+    }
+
+    fn part2((points, splits): &Self::Input) -> Result<String> {
+        let code = code(points, splits)?;
+
+        // double every block so that the letters are about as wide as they are high
+        let blocks = code.map(|dot| {
+            let ch = if *dot { BLOCK_SOLID } else { BLOCK_LIGHT };
+            format!("{}{}", ch, ch)
+        });
+
+        Ok(blocks.to_string())
+    }
+
+    fn render((points, splits): &Self::Input, part: usize) -> Result<Option<Image>> {
+        if part == 2 {
+            Ok(Some(Image::Bitmap(code(points, splits)?)))
+        } else {
+            Ok(None)
+        }
+    }
+}
@@ -0,0 +1,137 @@
+use crate::parse::Block;
+use crate::prelude::*;
+use std::collections::HashMap;
+
+#[inline]
+const fn bitpack(a: u8, b: u8) -> usize {
+    (a - b'A') as usize | (((b - b'A') as usize) * 32)
+}
+
+#[inline]
+const fn bitunpack(x: usize) -> (u8, u8) {
+    let a = x % 32;
+    let b = (x / 32) % 32;
+    (a as u8 + b'A', b as u8 + b'A')
+}
+
+const BITPACK_MAX: usize = bitpack(b'Z', b'Z');
+
+/// The quantity of the most common element minus the quantity of the least common one
+pub fn score(histogram: &[usize; 256]) -> usize {
+    histogram.iter().max().unwrap_or(&0) - histogram.iter().filter(|c| **c > 0).min().unwrap_or(&0)
+}
+
+/// The element inserted between each pair of elements
+pub type Rules = HashMap<[u8; 2], u8>;
+
+/// Parses the polymer template and the pair insertion rules
+pub fn parse(input: &str) -> Result<(Vec<u8>, Rules)> {
+    let (start, rules) = Block::new(input).header_body()?;
+    let start = start.single_line(|line| Ok(line.as_bytes().to_vec()))?;
+
+    let rules = rules
+        .map_lines(|line| {
+            let (from, to) = line.split_once(" -> ").ok_or(Error::PatternMatch)?;
+            let from: [u8; 2] = from.as_bytes().try_into()?;
+            let to = to.bytes().next().ok_or(Error::EmptyIterator).at_column(line.len() + 1)?;
+            Ok((from, to))
+        })?
+        .into_iter()
+        .collect::<HashMap<_, _>>();
+
+    Ok((start, rules))
+}
+
+pub struct Day14;
+
+impl Solution for Day14 {
+    const DAY: usize = 14;
+
+    type Input = (Vec<u8>, Rules);
+    type Part1 = usize;
+    type Part2 = usize;
+
+    fn parse(input: &str) -> Result<Self::Input> {
+        parse(input)
+    }
+
+    fn part1((start, rules): &Self::Input) -> Result<usize> {
+        let mut current = start.clone();
+        for _i in 1..=10 {
+            let mut result = Vec::with_capacity(current.len() * 3 / 2);
+            if let Some(first) = current.first() {
+                result.push(*first);
+            }
+            current.array_windows().for_each(|window| {
+                if let Some(to_insert) = rules.get(window) {
+                    result.push(*to_insert)
+                }
+                result.push(window[1]);
+            });
+
+            current = result;
+        }
+
+        let mut histogram = [0_usize; 256];
+        current.iter().for_each(|b| {
+            histogram[*b as usize] += 1;
+        });
+        let part1 = score(&mut histogram);
+
+        debug!(
+            "B: {}, C: {}, H: {}, N: {}",
+            histogram[b'B' as usize], histogram[b'C' as usize], histogram[b'H' as usize], histogram[b'N' as usize]
+        );
+
+        Ok(part1)
+    }
+
+    fn part2((start, rules): &Self::Input) -> Result<usize> {
+        let mut pair_histogram = vec![0_usize; BITPACK_MAX + 1];
+
+        start.array_windows().for_each(|[a, b]| {
+            pair_histogram[bitpack(*a, *b)] += 1;
+        });
+        let mut next_pair_histogram = vec![0_usize; pair_histogram.len()];
+
+        for _i in 1..=40 {
+            next_pair_histogram.fill(0);
+            for (j, count) in pair_histogram.iter().enumerate() {
+                if *count > 0 {
+                    let (a, b) = bitunpack(j);
+                    if let Some(to_insert) = rules.get(&[a, b]) {
+                        next_pair_histogram[bitpack(a, *to_insert)] += *count;
+                        next_pair_histogram[bitpack(*to_insert, b)] += *count;
+                    } else {
+                        next_pair_histogram[bitpack(a, b)] = *count;
+                    }
+                }
+            }
+
+            std::mem::swap(&mut pair_histogram, &mut next_pair_histogram);
+        }
+
+        let mut histogram = [0_usize; 256];
+
+        for (i, count) in pair_histogram.iter().enumerate() {
+            if *count > 0 {
+                let (a, _b) = bitunpack(i);
+                histogram[a as usize] += *count;
+            }
+        }
+
+        // last character stays the same over iterations and we are missing it's outgoing edge
+        if let Some(last) = start.last() {
+            histogram[*last as usize] += 1;
+        }
+
+        let part2 = score(&histogram);
+
+        debug!(
+            "B: {}, C: {}, H: {}, N: {}",
+            histogram[b'B' as usize], histogram[b'C' as usize], histogram[b'H' as usize], histogram[b'N' as usize]
+        );
+
+        Ok(part2)
+    }
+}
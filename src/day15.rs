@@ -0,0 +1,60 @@
+use crate::prelude::*;
+use crate::search::{astar, dijkstra};
+
+/// Finds the path from the top left to the bottom right corner entering the fewest risky cells
+///
+/// Every cell has a risk of at least 1, so the Manhattan distance to the corner never overestimates.
+pub fn lowest_total_risk(map: &Grid<u8>) -> Result<usize> {
+    let goal = (map.width() - 1, map.height() - 1);
+    let path = astar(
+        (0, 0),
+        |position| map.neighbours4(*position).map(|n| (n, map[n] as usize)),
+        |(x, y)| (goal.0 - x) + (goal.1 - y),
+        |position| *position == goal,
+    );
+
+    path.map(|path| path.cost).ok_or_else(|| Error::General("No path to the bottom right corner".into()))
+}
+
+/// The full map, five times the tile in each direction with the risk levels increasing and wrapping from 9 to 1
+pub fn enlarge(map: &Grid<u8>) -> Grid<u8> {
+    Grid::from_fn(map.width() * 5, map.height() * 5, |x, y| {
+        let value = map[(x % map.width(), y % map.height())] as usize + x / map.width() + y / map.height();
+        if value > 9 {
+            (value - 9) as u8
+        } else {
+            value as u8
+        }
+    })
+}
+
+pub struct Day15;
+
+impl Solution for Day15 {
+    const DAY: usize = 15;
+
+    type Input = Grid<u8>;
+    type Part1 = usize;
+    type Part2 = usize;
+
+    fn parse(input: &str) -> Result<Self::Input> {
+        Grid::parse_digits(input)
+    }
+
+    fn part1(map: &Self::Input) -> Result<usize> {
+        lowest_total_risk(map)
+    }
+
+    fn part2(map: &Self::Input) -> Result<usize> {
+        lowest_total_risk(&enlarge(map))
+    }
+
+    fn alternatives() -> Vec<Alternative<Self::Input>> {
+        vec![Alternative::new("part2_dijkstra", |map: &Self::Input| {
+            let map = enlarge(map);
+            let goal = (map.width() - 1, map.height() - 1);
+            let path = dijkstra((0, 0), |p| map.neighbours4(*p).map(|n| (n, map[n] as usize)), |p| *p == goal);
+            Ok(path.ok_or(Error::EmptyIterator)?.cost.to_string())
+        })]
+    }
+}
@@ -0,0 +1,163 @@
+use crate::parse::Block;
+use crate::prelude::*;
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum Packet {
+    Number {
+        version: u64,
+        value: u64,
+    },
+    Operator {
+        version: u64,
+        op_type: u64,
+        data: Vec<Packet>,
+    },
+}
+
+/// The bits of a hexadecimal transmission, most significant first
+pub fn parse_hex(input: &str) -> Result<Vec<bool>> {
+    let mut bits = Vec::with_capacity(input.len() * 4);
+    for (i, ch) in input.chars().enumerate() {
+        let digit = ch
+            .to_digit(16)
+            .ok_or_else(|| Error::General(format!("Unexpected hex digit {}", ch)))
+            .at_column(i + 1)?;
+        bits.extend((0..4).rev().map(|i| (digit & (1 << i)) != 0));
+    }
+    Ok(bits)
+}
+
+fn read_bits(bits: &mut dyn Iterator<Item = bool>, num_bits: usize) -> u64 {
+    bits.take(num_bits).fold(0, |a, b| a << 1 | b as u64)
+}
+
+fn read_num(bits: &mut dyn Iterator<Item = bool>) -> u64 {
+    let mut num = 0;
+    loop {
+        if let Some(cont) = bits.next() {
+            num = num << 4 | read_bits(bits, 4);
+
+            if !cont {
+                break;
+            }
+        } else {
+            break;
+        }
+    }
+
+    num
+}
+
+/// Reads a single packet with all its sub-packets, `None` once the bits run out
+pub fn parse_packet(mut bits: &mut dyn Iterator<Item = bool>) -> Option<Packet> {
+    let version = read_bits(bits, 3);
+    let packet_type = read_bits(bits, 3);
+
+    let packet = match packet_type {
+        4 => {
+            let value = read_num(bits);
+            Packet::Number { version, value }
+        }
+        _ => {
+            let length_type = bits.next();
+            let packets = match length_type {
+                None => return None,
+                Some(false) => {
+                    let num_bits = read_bits(&mut bits, 15) as usize;
+
+                    let mut bits = bits.take(num_bits);
+                    parse_packets(&mut bits, None)
+                }
+                Some(true) => {
+                    let num_packets = read_bits(&mut bits, 11) as usize;
+                    parse_packets(&mut bits, Some(num_packets))
+                }
+            };
+
+            Packet::Operator {
+                version,
+                op_type: packet_type,
+                data: packets,
+            }
+        }
+    };
+
+    Some(packet)
+}
+
+/// Reads packets until the bits run out or `limit` packets are read
+pub fn parse_packets(bits: &mut dyn Iterator<Item = bool>, limit: Option<usize>) -> Vec<Packet> {
+    let mut packets = vec![];
+    loop {
+        if let Some(limit) = limit {
+            if packets.len() >= limit {
+                break;
+            }
+        }
+        if let Some(packet) = parse_packet(bits) {
+            packets.push(packet);
+        } else {
+            break;
+        }
+    }
+    packets
+}
+
+/// Sum of the versions of all packets and sub-packets
+pub fn version_sum(packets: &[Packet]) -> u64 {
+    packets.iter().fold(0_u64, |a, p| {
+        a + match p {
+            Packet::Number { version, .. } => *version,
+            Packet::Operator { version, data, .. } => *version + version_sum(data),
+        }
+    })
+}
+
+/// The value of the expression a packet encodes
+pub fn evaluate(packet: &Packet) -> u64 {
+    match packet {
+        Packet::Number { value, .. } => *value,
+        Packet::Operator { op_type, data, .. } => match op_type {
+            0 => data.iter().map(evaluate).sum(),
+            1 => data.iter().map(evaluate).product(),
+            2 => data.iter().map(evaluate).min().unwrap(),
+            3 => data.iter().map(evaluate).max().unwrap(),
+            5 => (evaluate(&data[0]) > evaluate(&data[1])) as u64,
+            6 => (evaluate(&data[0]) < evaluate(&data[1])) as u64,
+            7 => (evaluate(&data[0]) == evaluate(&data[1])) as u64,
+            _ => {
+                panic!("Unsupported operator {}", op_type);
+            }
+        },
+    }
+}
+
+/// Parses the packets of a hexadecimal transmission
+pub fn parse(input: &str) -> Result<Vec<Packet>> {
+    let mut bits = Block::new(input).single_line(|line| parse_hex(line.trim()))?.into_iter();
+    Ok(parse_packets(&mut bits, None))
+}
+
+pub struct Day16;
+
+impl Solution for Day16 {
+    const DAY: usize = 16;
+
+    type Input = Vec<Packet>;
+    type Part1 = u64;
+    type Part2 = u64;
+
+    fn parse(input: &str) -> Result<Self::Input> {
+        parse(input)
+    }
+
+    fn part1(packets: &Self::Input) -> Result<u64> {
+        Ok(version_sum(packets))
+    }
+
+    fn part2(packets: &Self::Input) -> Result<u64> {
+        let packet = packets.first().ok_or(Error::EmptyIterator)?;
+
+        Ok(evaluate(packet))
+    }
+}
@@ -0,0 +1,92 @@
+use crate::prelude::*;
+
+type Point = Point2<i64>;
+
+/// Launches the probe with all velocities in the given ranges, see [`solve`]
+pub fn simulate(target: &(Point, Point), min_vx: i64, max_vx: i64, min_vy: i64, max_vy: i64) -> (Option<i64>, usize) {
+    let mut max_y: Option<i64> = None;
+    let mut count = 0;
+
+    for initial_vx in min_vx..max_vx+1 {
+        for initial_vy in min_vy..max_vy+1 {
+            let mut velocity = Point::new(initial_vx, initial_vy);
+            let mut position = Point::default();
+            let mut current_max_y = 0;
+
+            loop {
+                current_max_y = current_max_y.max(position.y);
+
+                if position.is_within(target.0, target.1) {
+                    max_y = max_y.map(|m| m.max(current_max_y)).or(Some(current_max_y));
+                    count += 1;
+                    break;
+                }
+
+                if position.x > target.1.x || position.y < target.0.y {
+                    break;
+                }
+
+                position += velocity;
+                // drag slows down towards 0 horizontally, gravity pulls down
+                velocity -= Point::new(velocity.x.signum(), 1);
+            }
+
+            // dbg!(initial_vx, initial_vy, current_max_y, max_y);
+        }
+    }
+
+
+    (max_y, count)
+}
+
+/// Parses the target area, returning its smallest and largest corner
+pub fn parse(input: &str) -> Result<(Point, Point)> {
+    let pattern = regex!(r"^target area: x=(-?[0-9]+)\.\.(-?[0-9]+), y=(-?[0-9]+)\.\.(-?[0-9]+)$");
+    let captures = pattern.captures(input.trim()).ok_or(Error::PatternMatch)?;
+
+    let x1: i64 = parse_capture(&captures, 1)?;
+    let x2: i64 = parse_capture(&captures, 2)?;
+    let y1: i64 = parse_capture(&captures, 3)?;
+    let y2: i64 = parse_capture(&captures, 4)?;
+
+    Point::bounding_box([Point::new(x1, y1), Point::new(x2, y2)]).ok_or(Error::EmptyIterator)
+}
+
+/// The highest position of all velocities which hit the target area and the number of these velocities
+pub fn solve(area: &(Point, Point)) -> (Option<i64>, usize) {
+    // very stupid guesses in which range the velocities will be
+    let min_vx = 1;
+    let max_vx = area.1.x;
+    let min_vy = area.0.y;
+    let max_vy = 10_000;
+
+    debug!("Velocities x {}..={}, y {}..={}", min_vx, max_vx, min_vy, max_vy);
+
+    simulate(area, min_vx, max_vx, min_vy, max_vy)
+}
+
+pub struct Day17;
+
+impl Solution for Day17 {
+    const DAY: usize = 17;
+
+    type Input = (Point, Point);
+    type Part1 = i64;
+    type Part2 = usize;
+
+    fn parse(input: &str) -> Result<Self::Input> {
+        parse(input)
+    }
+
+    fn part1(area: &Self::Input) -> Result<i64> {
+        let (part1, _) = solve(area);
+
+        part1.ok_or_else(|| Error::General("No solution to part1".into()))
+    }
+
+    fn part2(area: &Self::Input) -> Result<usize> {
+        let (_, part2) = solve(area);
+
+        Ok(part2)
+    }
+}
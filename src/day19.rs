@@ -0,0 +1,279 @@
+use crate::parse::Block;
+use crate::prelude::*;
+
+type Matrix = [[i32; 3]; 3];
+type Vector = Point3<i32>;
+
+const IDENTITY: Matrix = [[1, 0, 0], [0, 1, 0], [0, 0, 1]];
+
+const fn int_sin(angle: i32) -> i32 {
+    match angle {
+        0 => 0,
+        90 => 1,
+        180 => 0,
+        270 => -1,
+        _ => panic!("sin: unsupported angle"),
+    }
+}
+
+const fn int_cos(angle: i32) -> i32 {
+    match angle {
+        0 => 1,
+        90 => 0,
+        180 => -1,
+        270 => 0,
+        _ => panic!("cos: unsupported angle"),
+    }
+}
+
+const fn rotate_x(angle: i32) -> Matrix {
+    let mut m = IDENTITY;
+    let s = int_sin(angle);
+    let c = int_cos(angle);
+    m[1][1] = c;
+    m[1][2] = -s;
+    m[2][1] = s;
+    m[2][2] = c;
+    m
+}
+
+const fn rotate_y(angle: i32) -> Matrix {
+    let mut m = IDENTITY;
+    let s = int_sin(angle);
+    let c = int_cos(angle);
+    m[0][0] = c;
+    m[0][2] = s;
+    m[2][0] = -s;
+    m[2][2] = c;
+    m
+}
+
+const fn rotate_z(angle: i32) -> Matrix {
+    let mut m = IDENTITY;
+    let s = int_sin(angle);
+    let c = int_cos(angle);
+    m[0][0] = c;
+    m[0][1] = -s;
+    m[1][0] = s;
+    m[1][1] = c;
+    m
+}
+
+const fn matrix_mul(a: &Matrix, b: &Matrix) -> Matrix {
+    let mut m = [[0; 3]; 3];
+    // for loops are not supported in const fn
+    let mut i = 0;
+    while i < 3 {
+        let mut j = 0;
+        while j < 3 {
+            m[i][j] = a[i][0] * b[0][j] + a[i][1] * b[1][j] + a[i][2] * b[2][j];
+            j += 1;
+        }
+        i += 1;
+    }
+    m
+}
+
+#[allow(unused)]
+const fn rotate_axis(v: &Vector, angle: i32) -> Matrix {
+    let s = int_sin(angle);
+    let c = int_cos(angle);
+
+    let c1 = 1 - c;
+    let x2 = v.x * v.x;
+    let y2 = v.y * v.y;
+    let z2 = v.z * v.z;
+
+    let xy = v.x * v.y;
+    let xz = v.x * v.z;
+    let yz = v.y * v.z;
+
+    let xs = v.x * s;
+    let ys = v.y * s;
+    let zs = v.z * s;
+
+    let mut m = [[0; 3]; 3];
+
+    m[0][0] = x2 * c1 + c;
+    m[0][1] = xy * c1 - zs;
+    m[0][2] = xz * c1 + ys;
+
+    m[1][0] = xy * c1 + zs;
+    m[1][1] = y2 * c1 + c;
+    m[1][2] = yz * c1 - xs;
+
+    m[2][0] = xz * c1 - ys;
+    m[2][1] = yz * c1 + xs;
+    m[2][2] = z2 * c1 + c;
+
+    m
+}
+
+const fn transform(v: &Vector, m: &Matrix) -> Vector {
+    Point3 {
+        x: v.x * m[0][0] + v.y * m[0][1] + v.z * m[0][2],
+        y: v.x * m[1][0] + v.y * m[1][1] + v.z * m[1][2],
+        z: v.x * m[2][0] + v.y * m[2][1] + v.z * m[2][2],
+    }
+}
+
+fn normalize_coordinates(coordinates: &mut [Vector]) -> Option<Vector> {
+    // coordinates.sort();
+    if let Some((first, rest)) = coordinates.split_first_mut() {
+        rest.iter_mut().for_each(|v| *v -= *first);
+        let res = *first;
+        *first = Vector::default();
+        Some(res)
+    } else {
+        None
+    }
+}
+
+fn rotate_coordinates(coordinates: &mut [Vector], m: &Matrix) {
+    coordinates.iter_mut().for_each(|v| {
+        *v = transform(v, m);
+    });
+}
+
+fn translate_coordinates(coordinates: &mut [Vector], v: &Vector) {
+    coordinates.iter_mut().for_each(|c| *c += *v);
+}
+
+/// Parses the beacons relative to each scanner
+pub fn parse(input: &str) -> Result<Vec<Vec<Vector>>> {
+    let scanners = Block::new(input)
+        .blocks()
+        .into_iter()
+        .map(|scanner| {
+            let ((n, header), beacons) = scanner.split_first_line()?;
+            if !header.starts_with("--- scanner ") {
+                return Err(Error::General("Expected a scanner header".into())).at_line(n, header);
+            }
+            beacons.map_lines(Vector::from_str)
+        })
+        .collect::<Result<Vec<_>>>()?;
+
+    Ok(scanners)
+}
+
+/// Aligns all scanners to the first one, returning the deduplicated beacons and the scanner positions
+pub fn align(mut scanners: Vec<Vec<Vector>>) -> (Vec<Vector>, Vec<Vector>) {
+    assert_eq!(IDENTITY, matrix_mul(&IDENTITY, &IDENTITY));
+
+    const ANGLES: [i32; 4] = [0, 90, 180, 270];
+    let mut rotations = ANGLES
+        .iter()
+        .flat_map(|x| {
+            ANGLES.iter().flat_map(|y| {
+                ANGLES.iter().map(|z| {
+                    let mx = rotate_x(*x);
+                    let my = rotate_y(*y);
+                    let mz = rotate_z(*z);
+                    let tmp = matrix_mul(&mx, &my);
+                    matrix_mul(&tmp, &mz)
+                })
+            })
+        })
+        .collect::<Vec<_>>();
+    rotations.sort();
+    rotations.dedup();
+
+    assert_eq!(rotations.len(), 24);
+
+    let scanner_len = scanners.len();
+
+    let mut first_coords = scanners.remove(0);
+    normalize_coordinates(&mut first_coords);
+
+    let mut aligned: Vec<(Vec<Vector>, Vector)> = Vec::with_capacity(scanner_len);
+    aligned.push((first_coords, Vector::default()));
+
+    while !scanners.is_empty() {
+        let found = scanners.iter().enumerate().find_map(|(i, coords)| {
+            rotations.iter().find_map(|rotate_matrix| {
+                let mut rotated_coords = coords.clone();
+                rotate_coordinates(&mut rotated_coords, rotate_matrix);
+
+                aligned
+                    .iter()
+                    .find_map(|(aligned_coords, _)| {
+                        // try to align on all possible pairs of beacons and count the overlap
+                        aligned_coords.iter().find_map(|ac| {
+                            rotated_coords.iter().find_map(|rc| {
+                                let count_intersect = aligned_coords
+                                    .iter()
+                                    .filter(|a| rotated_coords.iter().any(|r| **a - *ac == *r - *rc))
+                                    .take(12) // stop once we found 12 matches
+                                    .count();
+                                if count_intersect >= 12 {
+                                    Some(*ac - *rc)
+                                } else {
+                                    None
+                                }
+                            })
+                        })
+                    })
+                    .map(|offset| {
+                        translate_coordinates(&mut rotated_coords, &offset);
+                        (i, rotated_coords, offset)
+                    })
+            })
+        });
+
+        if let Some((index, coords, offset)) = found {
+            aligned.push((coords, offset));
+            scanners.remove(index);
+        } else {
+            panic!("Found no matching scanners");
+        }
+    }
+
+    let scanner_positions = aligned.iter().map(|(_, offset)| *offset).collect::<Vec<_>>();
+    let mut deduplicated = aligned
+        .into_iter()
+        .map(|(coords, _)| coords)
+        .flatten()
+        .collect::<Vec<_>>();
+    deduplicated.sort();
+    deduplicated.dedup();
+
+    (deduplicated, scanner_positions)
+}
+
+pub struct Day19;
+
+impl Solution for Day19 {
+    const DAY: usize = 19;
+
+    type Input = Vec<Vec<Vector>>;
+    type Part1 = usize;
+    type Part2 = i32;
+
+    fn parse(input: &str) -> Result<Self::Input> {
+        parse(input)
+    }
+
+    fn part1(scanners: &Self::Input) -> Result<usize> {
+        let (beacons, _) = align(scanners.clone());
+
+        Ok(beacons.len())
+    }
+
+    fn part2(scanners: &Self::Input) -> Result<i32> {
+        let (_, scanner_positions) = align(scanners.clone());
+
+        let max_manhattan = scanner_positions
+            .iter()
+            .map(|v1| {
+                scanner_positions
+                    .iter()
+                    .filter(|v2| !std::ptr::eq(v1, *v2))
+                    .map(|v2| v1.manhattan_distance(*v2))
+            })
+            .flatten()
+            .max()
+            .ok_or(Error::EmptyIterator)?;
+
+        Ok(max_manhattan)
+    }
+}
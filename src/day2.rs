@@ -0,0 +1,195 @@
+use crate::parse::Block;
+use crate::prelude::*;
+use std::fmt::{Display, Formatter};
+use std::io::Write;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Command {
+    Forward(u32),
+    Down(u32),
+    Up(u32),
+}
+
+/// Parses a command like `forward 5`
+impl FromStr for Command {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self> {
+        let (name, units) = s
+            .split_once(' ')
+            .ok_or_else(|| Error::General("Expected a command and a number of units".into()))?;
+        let command: fn(u32) -> Command = match name {
+            "forward" => Command::Forward,
+            "down" => Command::Down,
+            "up" => Command::Up,
+            _ => return Err(Error::General(format!("Unknown command {}", name))).at_column(1),
+        };
+        Ok(command(u32::from_str(units).at_column(name.len() + 2)?))
+    }
+}
+
+impl Display for Command {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Command::Forward(units) => write!(f, "forward {}", units),
+            Command::Down(units) => write!(f, "down {}", units),
+            Command::Up(units) => write!(f, "up {}", units),
+        }
+    }
+}
+
+/// Where the submarine is, depths increase downwards
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct State {
+    pub position: i64,
+    pub depth: i64,
+    pub aim: i64,
+}
+
+/// How the submarine interprets the commands
+pub trait Model {
+    fn apply(&self, state: &mut State, command: Command);
+}
+
+impl<M: Model + ?Sized> Model for &M {
+    fn apply(&self, state: &mut State, command: Command) {
+        (*self).apply(state, command)
+    }
+}
+
+/// `down` and `up` change the depth directly, like the first part assumes
+#[derive(Debug, Clone, Copy, Default)]
+pub struct Plain;
+
+impl Model for Plain {
+    fn apply(&self, state: &mut State, command: Command) {
+        match command {
+            Command::Forward(units) => state.position += units as i64,
+            Command::Down(units) => state.depth += units as i64,
+            Command::Up(units) => state.depth -= units as i64,
+        }
+    }
+}
+
+/// `down` and `up` change the aim, `forward` moves along it, like the manual of the second part says
+#[derive(Debug, Clone, Copy, Default)]
+pub struct Aim;
+
+impl Model for Aim {
+    fn apply(&self, state: &mut State, command: Command) {
+        match command {
+            Command::Forward(units) => {
+                state.position += units as i64;
+                state.depth += state.aim * units as i64;
+            }
+            Command::Down(units) => state.aim += units as i64,
+            Command::Up(units) => state.aim -= units as i64,
+        }
+    }
+}
+
+/// A submarine following commands according to a movement [`Model`]
+#[derive(Debug, Clone)]
+pub struct Submarine<M> {
+    model: M,
+    state: State,
+    steps: usize,
+}
+
+impl<M: Model> Submarine<M> {
+    pub fn new(model: M) -> Self {
+        Submarine {
+            model,
+            state: State::default(),
+            steps: 0,
+        }
+    }
+
+    pub fn execute(&mut self, command: Command) -> State {
+        self.model.apply(&mut self.state, command);
+        self.steps += 1;
+        self.state
+    }
+
+    pub fn state(&self) -> State {
+        self.state
+    }
+
+    /// Number of commands executed so far
+    pub fn steps(&self) -> usize {
+        self.steps
+    }
+
+    /// Executes all commands, returning where they lead
+    pub fn run(&mut self, commands: &[Command]) -> State {
+        commands.iter().for_each(|command| {
+            self.execute(*command);
+        });
+        self.state
+    }
+
+    /// Executes all commands, writing the course as CSV with a row for the start and after each command
+    pub fn write_trajectory(&mut self, w: &mut impl Write, commands: &[Command]) -> Result<()> {
+        writeln!(w, "step,position,depth")?;
+        writeln!(w, "{},{},{}", self.steps, self.state.position, self.state.depth)?;
+        for command in commands {
+            let state = self.execute(*command);
+            writeln!(w, "{},{},{}", self.steps, state.position, state.depth)?;
+        }
+        Ok(())
+    }
+}
+
+/// Parses one command per line
+pub fn parse(input: &str) -> Result<Vec<Command>> {
+    Block::new(input).map_lines(Command::from_str)
+}
+
+pub struct Day2;
+
+impl Solution for Day2 {
+    const DAY: usize = 2;
+
+    type Input = Vec<Command>;
+    type Part1 = i64;
+    type Part2 = i64;
+
+    fn parse(input: &str) -> Result<Self::Input> {
+        parse(input)
+    }
+
+    fn part1(commands: &Self::Input) -> Result<i64> {
+        let state = Submarine::new(Plain).run(commands);
+
+        Ok(state.position * state.depth)
+    }
+
+    fn part2(commands: &Self::Input) -> Result<i64> {
+        let state = Submarine::new(Aim).run(commands);
+
+        Ok(state.position * state.depth)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_submarine() {
+        let commands = parse("forward 5\ndown 5\nforward 8\nup 3\ndown 8\nforward 2\n").unwrap();
+        assert_eq!(Submarine::new(Plain).run(&commands), State { position: 15, depth: 10, aim: 0 });
+
+        let mut csv = vec![];
+        Submarine::new(&Aim as &dyn Model).write_trajectory(&mut csv, &commands[..3]).unwrap();
+        assert_eq!(String::from_utf8(csv).unwrap(), "step,position,depth\n0,0,0\n1,5,0\n2,5,0\n3,13,40\n");
+
+        let error = |input| parse(input).unwrap_err().to_string();
+        assert_eq!(
+            error("forward 5\nbackward 2"),
+            "line 2, column 1: General: Unknown command backward in \"backward 2\""
+        );
+        assert_eq!(error("backward x"), "line 1, column 1: General: Unknown command backward in \"backward x\"");
+        assert_eq!(error("up -3"), "line 1, column 4: Parse: invalid digit found in string in \"up -3\"");
+    }
+}
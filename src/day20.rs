@@ -0,0 +1,156 @@
+use crate::parse::Block;
+use crate::prelude::*;
+use crate::sparse::Position;
+
+const DIR: [(isize, isize); 9] = [
+    (-1, -1),
+    (0, -1),
+    (1, -1),
+    (-1, 0),
+    (0, 0),
+    (1, 0),
+    (-1, 1),
+    (0, 1),
+    (1, 1),
+];
+
+fn get_index(image: &SparseGrid<bool>, position: Position) -> usize {
+    DIR.iter().fold(0_usize, |a, (dx, dy)| {
+        let bit = *image.get(position + Position::new(*dx, *dy));
+        (a << 1) | (bit as usize)
+    })
+}
+
+/// Enhances the image once, it grows by one pixel on each side
+///
+/// The pixels beyond turn into the lookup of the background, so that the image stays finite on the infinite plane.
+pub fn enhance_step(image: &SparseGrid<bool>, lookup: &[bool]) -> SparseGrid<bool> {
+    let background = lookup[if *image.background() { 511 } else { 0 }];
+    let mut next_image = SparseGrid::new(background);
+    let (min, max) = image.bounds().unwrap_or_default();
+    for y in min.y - 1..=max.y + 1 {
+        for x in min.x - 1..=max.x + 1 {
+            let position = Position::new(x, y);
+            next_image.set(position, lookup[get_index(image, position)]);
+        }
+    }
+    next_image
+}
+
+/// Enhances the image `iterations` times
+pub fn enhance(original_image: &Grid<bool>, lookup: &[bool], iterations: usize) -> SparseGrid<bool> {
+    let mut image = SparseGrid::from_grid(original_image, false);
+    for _ in 0..iterations {
+        image = enhance_step(&image, lookup);
+    }
+    image
+}
+
+/// Number of lit pixels after enhancing the image `iterations` times
+pub fn count_lit(image: &Grid<bool>, lookup: &[bool], iterations: usize) -> Result<usize> {
+    let image = enhance(image, lookup, iterations);
+    if *image.background() {
+        return Err(Error::General("Infinitely many pixels are lit".into()));
+    }
+
+    Ok(image.len())
+}
+
+/// The image enhanced step by step
+struct Enhancement {
+    lookup: Vec<bool>,
+    image: SparseGrid<bool>,
+}
+
+impl Simulation for Enhancement {
+    fn step(&mut self) -> bool {
+        self.image = enhance_step(&self.image, &self.lookup);
+        true
+    }
+
+    fn render(&self) -> String {
+        let lit = if *self.image.background() { "infinitely many".to_string() } else { self.image.len().to_string() };
+        format!("{}\n{} lit", self.image.to_grid().map(|b| if *b { '#' } else { '.' }), lit)
+    }
+
+    fn image(&self) -> Option<Image> {
+        Some(Image::Bitmap(self.image.to_grid()))
+    }
+}
+
+fn parse_pixel(ch: char) -> Result<bool> {
+    match ch {
+        '#' => Ok(true),
+        '.' => Ok(false),
+        _ => Err(Error::General(format!("Unexpected pixel {}", ch))),
+    }
+}
+
+/// Parses the image enhancement algorithm and the input image
+pub fn parse(input: &str) -> Result<(Vec<bool>, Grid<bool>)> {
+    let (lookup, image) = Block::new(input).header_body()?;
+    let lookup = lookup.single_line(|line| {
+        let lookup = line.chars().map(parse_pixel).collect::<Result<Vec<_>>>()?;
+        if lookup.len() != 512 {
+            return Err(Error::General(format!("Lookup table has {} entries, expected 512", lookup.len())));
+        }
+        Ok(lookup)
+    })?;
+
+    Ok((lookup, image.grid(parse_pixel)?))
+}
+
+pub struct Day20;
+
+impl Solution for Day20 {
+    const DAY: usize = 20;
+
+    type Input = (Vec<bool>, Grid<bool>);
+    type Part1 = usize;
+    type Part2 = usize;
+
+    fn parse(input: &str) -> Result<Self::Input> {
+        parse(input)
+    }
+
+    fn part1((lookup, image): &Self::Input) -> Result<usize> {
+        count_lit(image, lookup, 2)
+    }
+
+    fn part2((lookup, image): &Self::Input) -> Result<usize> {
+        count_lit(image, lookup, 50)
+    }
+
+    fn render((lookup, image): &Self::Input, part: usize) -> Result<Option<Image>> {
+        let iterations = if part == 1 { 2 } else { 50 };
+
+        Ok(Some(Image::Bitmap(enhance(image, lookup, iterations).to_grid())))
+    }
+
+    fn simulation((lookup, image): &Self::Input) -> Option<Box<dyn Simulation>> {
+        Some(Box::new(Enhancement {
+            lookup: lookup.clone(),
+            image: SparseGrid::from_grid(image, false),
+        }))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn image(pixels: &[u8; 9]) -> SparseGrid<bool> {
+        SparseGrid::from_grid(&Grid::from_vec(3, pixels.iter().map(|b| *b == b'#').collect()).unwrap(), false)
+    }
+
+    #[test]
+    fn test_get_index() {
+        assert_eq!(get_index(&image(b"...#...#."), Position::new(1, 1)), 0b000100010);
+        assert_eq!(get_index(&image(b"#..#...#."), Position::new(1, 1)), 0b100100010);
+        assert_eq!(get_index(&image(b"#.##...#."), Position::new(1, 1)), 0b101100010);
+        assert_eq!(get_index(&image(b"........."), Position::new(1, 1)), 0b000000000);
+        assert_eq!(get_index(&image(b"........."), Position::new(0, 0)), 0b000000000);
+        assert_eq!(get_index(&image(b"........."), Position::new(0, 2)), 0b000000000);
+        assert_eq!(get_index(&image(b"........."), Position::new(2, 2)), 0b000000000);
+    }
+}
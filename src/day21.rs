@@ -0,0 +1,116 @@
+use crate::memo::Memo;
+use crate::parse::Block;
+use crate::prelude::*;
+
+/// Plays with the deterministic die, returns the losing score times the number of rolls
+pub fn solve_part1(mut positions: [usize; 2]) -> usize {
+    let mut scores = [0, 0];
+    let mut dice = 0_usize;
+    let mut rolls = 0_usize;
+    loop {
+        for i in 0..2 {
+            // die is zero-based
+            let roll = dice % 100 + (dice + 1) % 100 + (dice + 2) % 100 + 3;
+            rolls += 3;
+
+            dice += 3;
+            dice %= 100;
+            positions[i] += roll;
+            while positions[i] > 10 {
+                positions[i] -= 10;
+            }
+
+            scores[i] += positions[i];
+
+            if scores[i] >= 1000 {
+                return scores[0].min(scores[1]) * rolls;
+            }
+        }
+    }
+}
+
+const PROBABILITIES: [usize; 7] = [1, 3, 6, 7, 6, 3, 1];
+
+/// Positions, scores and the player to move
+type State = ([usize; 2], [usize; 2], usize);
+
+/// Counts the universes in which `player` wins and all universes, starting from the given state
+pub fn solve_part2(memo: &mut Memo<State, (usize, usize)>, positions: [usize; 2], scores: [usize; 2], player: usize) -> (usize, usize) {
+    memo.get_or_insert_with((positions, scores, player), |memo| {
+        if scores[player] >= 21 {
+            (1, 1)
+        } else if scores[1 - player] >= 21 {
+            (0, 1)
+        } else {
+            let mut new_positions = positions;
+            let mut new_scores = scores;
+
+            let mut wins = 0;
+            let mut universes = 0;
+
+            for (i, n) in PROBABILITIES.iter().enumerate() {
+                new_positions[player] = positions[player] + i + 3;
+                while new_positions[player] > 10 {
+                    new_positions[player] -= 10;
+                }
+                new_scores[player] = scores[player] + new_positions[player];
+
+                if new_scores[player] < 21 {
+                    let (wins1, universes1) = solve_part2(memo, new_positions, new_scores, 1 - player);
+                    wins += n * (universes1 - wins1);
+                    universes += n * universes1;
+                } else {
+                    wins += n;
+                    universes += n;
+                }
+            }
+
+            (wins, universes)
+        }
+    })
+}
+
+/// Parses the starting positions of both players
+pub fn parse(input: &str) -> Result<[usize; 2]> {
+    let pattern = regex!(r"^Player ([12]) starting position: (\d+)$");
+
+    let mut positions = [0; 2];
+    Block::new(input).map_lines(|line| {
+        let captures = pattern.captures(line).ok_or(Error::PatternMatch)?;
+        let player: usize = captures.get(1).unwrap().as_str().parse()?;
+        positions[player - 1] = captures.get(2).unwrap().as_str().parse()?;
+        Ok(())
+    })?;
+
+    if positions.iter().any(|p| *p < 1 || *p > 10) {
+        return Err(Error::General(format!("Invalid starting positions {:?}", positions)));
+    }
+
+    Ok(positions)
+}
+
+pub struct Day21;
+
+impl Solution for Day21 {
+    const DAY: usize = 21;
+
+    type Input = [usize; 2];
+    type Part1 = usize;
+    type Part2 = usize;
+
+    fn parse(input: &str) -> Result<Self::Input> {
+        parse(input)
+    }
+
+    fn part1(positions: &Self::Input) -> Result<usize> {
+        Ok(solve_part1(*positions))
+    }
+
+    fn part2(positions: &Self::Input) -> Result<usize> {
+        let mut memo = Memo::new();
+        let (wins, universes) = solve_part2(&mut memo, *positions, [0, 0], 0);
+        info!("Memo: {}", memo);
+
+        Ok(wins.max(universes - wins))
+    }
+}
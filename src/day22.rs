@@ -0,0 +1,121 @@
+use std::ops::Range;
+use regex::Captures;
+use crate::parse::Block;
+use crate::prelude::*;
+
+#[derive(Debug, Clone)]
+/// A reboot step, turning a cuboid on or off
+pub struct Cube {
+    pub on: bool,
+    pub x: Range<i32>,
+    pub y: Range<i32>,
+    pub z: Range<i32>,
+}
+
+fn parse_range(captures: &Captures, index: usize) -> Result<Range<i32>> {
+    let start: i32 = parse_capture(captures, index)?;
+    let end: i32 = parse_capture(captures, index + 1)?;
+
+    Ok(Range {
+        start: start.min(end),
+        end: start.max(end) + 1, // exclusive
+    })
+}
+
+/// Number of cubes which are on in the initialization region from -50 to 50
+pub fn solve_part1(cubes: &[Cube]) -> usize {
+    let mut space = vec![vec![[false; 101]; 101]; 101];
+
+    for cube in cubes {
+        if [cube.x.start as i32, cube.x.end as i32, cube.y.start, cube.y.end, cube.z.start as i32, cube.z.end as i32].iter()
+            .all(|r| *r >= -50 && *r <= 51 ) {
+            for x in cube.x.clone() {
+                for y in cube.y.clone() {
+                    for z in cube.z.clone() {
+                        space[(x + 50) as usize][(y + 50) as usize][(z + 50) as usize] = cube.on;
+                    }
+                }
+            }
+        }
+    }
+
+    space.iter().flatten().flatten().filter(|p| **p).count()
+}
+
+fn intersect(a: &Range<i32>, b: &Range<i32>) -> Option<Range<i32>> {
+    let range = a.start.max(b.start)..a.end.min(b.end);
+    if range.is_empty() {
+        None
+    } else {
+        Some(range)
+    }
+}
+
+fn volume(cube: &Cube) -> i64 {
+    cube.x.len() as i64 * cube.y.len() as i64 * cube.z.len() as i64
+}
+
+/// Number of cubes which are on after all reboot steps
+pub fn solve_part2(cubes: &[Cube]) -> usize {
+    // cuboids with a positive or negative sign, the intersection of each new cube with all previous
+    // cuboids gets added with the opposite sign, so overlapping regions are only counted once
+    let mut signed: Vec<(Cube, i64)> = Vec::with_capacity(cubes.len() * 16);
+
+    for cube in cubes {
+        let mut intersections = vec![];
+        for (other, sign) in signed.iter() {
+            if let (Some(x), Some(y), Some(z)) = (
+                intersect(&cube.x, &other.x),
+                intersect(&cube.y, &other.y),
+                intersect(&cube.z, &other.z),
+            ) {
+                intersections.push((Cube { on: cube.on, x, y, z }, -sign));
+            }
+        }
+        if cube.on {
+            intersections.push((cube.clone(), 1));
+        }
+        signed.extend(intersections);
+    }
+
+    signed.iter().map(|(cube, sign)| sign * volume(cube)).sum::<i64>() as usize
+}
+
+/// Parses one reboot step per line
+pub fn parse(input: &str) -> Result<Vec<Cube>> {
+    let pattern = regex!(r"^(on|off) x=(-?[0-9]+)\.\.(-?[0-9]+),y=(-?[0-9]+)\.\.(-?[0-9]+),z=(-?[0-9]+)\.\.(-?[0-9]+)$");
+
+    let cubes = Block::new(input).records(pattern, |captures| {
+        Ok(Cube {
+            on: &captures[1] == "on",
+            x: parse_range(captures, 2)?,
+            y: parse_range(captures, 4)?,
+            z: parse_range(captures, 6)?,
+        })
+    })?;
+
+    Ok(cubes)
+}
+
+pub struct Day22;
+
+impl Solution for Day22 {
+    const DAY: usize = 22;
+
+    type Input = Vec<Cube>;
+    type Part1 = usize;
+    type Part2 = usize;
+
+    fn parse(input: &str) -> Result<Self::Input> {
+        parse(input)
+    }
+
+    fn part1(cubes: &Self::Input) -> Result<usize> {
+        Ok(solve_part1(cubes))
+    }
+
+    fn part2(cubes: &Self::Input) -> Result<usize> {
+        trace!("Cubes take {} bytes", std::mem::size_of::<Cube>());
+        Ok(solve_part2(cubes))
+    }
+}
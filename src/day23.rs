@@ -0,0 +1,333 @@
+use crate::parse::Block;
+use crate::prelude::*;
+use crate::search::dijkstra_by_key;
+
+
+/*
+
+
+7+9+60+60+500+600+8000+9000
+#############
+#...........#
+###C#C#A#B###
+  #D#D#B#A#
+  #########
+
+#############
+#...B......A#
+###C#C#.#B###
+  #D#D#.#A#
+  #########
++5+50
+
+#############
+#AB.........#
+###C#C#.#B###
+  #D#D#.#A#
+  #########
++70
+
+#############
+#AB.........#
+###.#.#C#B###
+  #D#D#C#A#
+  #########
++500+600
+
+#############
+#AB.B.....A.#
+###.#.#C#.###
+  #D#D#C#.#
+  #########
++60+3
+
+#############
+#AB.B.....A.#
+###.#.#C#.###
+  #D#.#C#D#
+  #########
++8000
+
+#############
+#AB.......A.#
+###.#.#C#.###
+  #D#B#C#D#
+  #########
++30
+
+#############
+#AB.......A.#
+###.#.#C#D###
+  #.#B#C#D#
+  #########
++9000
+
+#############
+#A........A.#
+###.#B#C#D###
+  #.#B#C#D#
+  #########
++40
+
+#############
+#...........#
+###.#B#C#D###
+  #.#B#C#D#
+  #########
++4+8
+
+
+
+
+18322 not right
+18326 not right
+18327 not right
+18318 not right
+17395 too low
+18342 too high
+18382 too high
+18386 too high
+
+
+
+part2
+
+#############
+#...........#
+###C#C#A#B###
+  #D#C#B#A#
+  #D#B#A#C#
+  #D#D#B#A#
+  #########
+
+7+60+8+70+700+700+700+40+5000+50+80+80+60+4+600+5+9000+11000+11000+11000+5+5+9+9
+
+
+500+500+40+10000+50+80+4+60+4+70+900+800+800
+
+
+
+50183 too high
+50192 too high
+*/
+
+const A: u8 = 0;
+const B: u8 = 1;
+const C: u8 = 2;
+const D: u8 = 3;
+
+/// Set of burrow nodes, grow it for burrows with more than 64 nodes
+type Nodes = Bitmap<1>;
+
+fn board_to_bitmap(board: &[(u8, u8)], color: u8) -> Nodes {
+    board.iter().filter(|(_, c)| *c == color).map(|(p, _)| *p as usize).collect::<Nodes>()
+}
+
+/// Finds the minimal energy to organize a burrow with rooms of depth 4, the board is given as a list of (node, color)
+pub fn solve(input: &[(u8, u8)]) -> Result<usize> {
+    #[rustfmt::skip]
+    let _nodes = [
+        00, 01, 02, 03, 04, 05, 06, 07, 08, 09, 10,
+                11,     12,     13,     14,
+                15,     16,     17,     18,
+                19,     20,     21,     22,
+                23,     24,     25,     26,
+    ];
+
+    let room1 = vec![23, 19, 15, 11];
+    let room2 = vec![24, 20, 16, 12];
+    let room3 = vec![25, 21, 17, 13];
+    let room4 = vec![26, 22, 18, 14];
+
+    let edges: Vec<(usize, usize, Nodes)> = {
+        let mut edges = vec![];
+
+        let mut edge = |room: &[usize], path: Vec<usize>| {
+            for i in 0..4 {
+                let from = room[i];
+                let to = *path.last().expect("non-empty path");
+                let path = room[i..].iter().chain(path.iter()).copied().collect::<Nodes>();
+                edges.push((from, to, path.unset(from)));
+                edges.push((to, from, path.unset(to)));
+            }
+        };
+
+        edge(&room1, vec![2, 1, 0]);
+        edge(&room1, vec![2, 1]);
+        edge(&room1, vec![2, 3]);
+        edge(&room1, vec![2, 3, 4, 5]);
+        edge(&room1, vec![2, 3, 4, 5, 6, 7]);
+        edge(&room1, vec![2, 3, 4, 5, 6, 7, 8, 9]);
+        edge(&room1, vec![2, 3, 4, 5, 6, 7, 8, 9, 10]);
+
+        edge(&room2, vec![4, 3, 2, 1, 0]);
+        edge(&room2, vec![4, 3, 2, 1]);
+        edge(&room2, vec![4, 3]);
+        edge(&room2, vec![4, 5]);
+        edge(&room2, vec![4, 5, 6, 7]);
+        edge(&room2, vec![4, 5, 6, 7, 8, 9]);
+        edge(&room2, vec![4, 5, 6, 7, 8, 9, 10]);
+
+        edge(&room3, vec![6, 5, 4, 3, 2, 1, 0]);
+        edge(&room3, vec![6, 5, 4, 3, 2, 1]);
+        edge(&room3, vec![6, 5, 4, 3]);
+        edge(&room3, vec![6, 5]);
+        edge(&room3, vec![6, 7]);
+        edge(&room3, vec![6, 7, 8, 9]);
+        edge(&room3, vec![6, 7, 8, 9, 10]);
+
+        edge(&room4, vec![8, 7, 6, 5, 4, 3, 2, 1, 0]);
+        edge(&room4, vec![8, 7, 6, 5, 4, 3, 2, 1]);
+        edge(&room4, vec![8, 7, 6, 5, 4, 3]);
+        edge(&room4, vec![8, 7, 6, 5]);
+        edge(&room4, vec![8, 7]);
+        edge(&room4, vec![8, 9]);
+        edge(&room4, vec![8, 9, 10]);
+
+        edges
+    };
+
+    let rooms_mask = [room1, room2, room3, room4].iter().map(|r| r.iter().copied().collect::<Nodes>()).collect::<Vec<_>>();
+    let hallway_mask = [0_usize, 1, 2, 3, 4, 5, 6, 7, 8, 9, 10].into_iter().collect::<Nodes>();
+    let entry_mask = [2_usize,4,6,8].into_iter().collect::<Nodes>();
+
+    const COST: [usize; 4] = [1, 10, 100, 1000];
+
+    #[rustfmt::skip]
+    let _goal: Vec<(u8, u8)> = vec![
+        (23, A), (19, A), (15, A), (11, A),
+        (24, B), (20, B), (16, B), (12, B),
+        (25, C), (21, C), (17, C), (13, C),
+        (26, D), (22, D), (18, D), (14, D),
+    ];
+
+    let goal = [
+        Nodes::default().set(23).set(19).set(15).set(11),
+        Nodes::default().set(24).set(20).set(16).set(12),
+        Nodes::default().set(25).set(21).set(17).set(13),
+        Nodes::default().set(26).set(22).set(18).set(14),
+    ];
+
+    let input = [
+        board_to_bitmap(input, A),
+        board_to_bitmap(input, B),
+        board_to_bitmap(input, C),
+        board_to_bitmap(input, D),
+    ];
+
+    // the state also tracks the room nodes amphipods moved into, they stay there, but only the board decides
+    // whether a state was seen before
+    let mut expanded = 0_usize;
+    let neighbours = |(board, room_was_dest): &([Nodes; 4], Nodes)| {
+        if expanded % 20_000 == 0 {
+            debug!("Expanded {} states", expanded);
+        }
+        expanded += 1;
+
+        let mut moves = vec![];
+        let occupied = board.iter().fold(Nodes::default(), |a, x| a | *x);
+
+        for (color, mask) in board.iter().enumerate() {
+            for pos in mask.iter() {
+                let is_hallway = hallway_mask.is_set(pos);
+                let possible_destinations = if is_hallway {
+                    rooms_mask[color] - *room_was_dest
+                } else if room_was_dest.is_set(pos) {
+                    continue;
+                } else {
+                    hallway_mask - entry_mask
+                };
+                let possible_destinations = possible_destinations - occupied;
+
+                for dest in possible_destinations.iter() {
+                    for (edge_from, edge_to, edge_path) in edges.iter() {
+                        if *edge_from == pos && *edge_to == dest && (*edge_path & occupied).is_empty() {
+                            let path_cost = COST[color] * edge_path.len();
+
+                            let new_room_was_dest = if is_hallway { room_was_dest.set(dest) } else { *room_was_dest };
+
+                            let mut new_board = *board;
+                            new_board[color] = new_board[color].unset(pos).set(dest);
+
+                            moves.push(((new_board, new_room_was_dest), path_cost));
+                        }
+                    }
+                }
+            }
+        }
+        moves
+    };
+
+    let path = dijkstra_by_key((input, Nodes::default()), |(board, _)| *board, neighbours, |(board, _)| *board == goal)
+        .ok_or_else(|| Error::General("Could not find a solution".into()))?;
+    info!("Expanded {} states, peak queue {}", path.stats.expanded, path.stats.peak_queue);
+
+    Ok(path.cost)
+}
+
+/// Parses the amphipod letters of the burrow diagram, returning them row by row from top to bottom
+pub fn parse(input: &str) -> Result<Vec<[u8; 4]>> {
+    let rows = Block::new(input)
+        .lines()
+        .skip(2)
+        .map(|(n, line)| (n, line, line.bytes().filter(|b| b.is_ascii_uppercase()).collect::<Vec<u8>>()))
+        .filter(|(_, _, row)| !row.is_empty())
+        .map(|(n, line, row)| {
+            let row: [u8; 4] = row
+                .try_into()
+                .map_err(|_| Error::General("Expected 4 amphipods per row".into()))
+                .at_line(n, line)?;
+            row.iter().try_for_each(|b| match b {
+                b'A'..=b'D' => Ok(()),
+                _ => Err(Error::General(format!("Unexpected amphipod {}", *b as char))).at_line(n, line),
+            })?;
+            Ok(row)
+        })
+        .collect::<Result<Vec<_>>>()?;
+
+    if rows.len() != 2 {
+        return Err(Error::General(format!("Expected 2 rows of amphipods, got {}", rows.len())));
+    }
+
+    Ok(rows)
+}
+
+/// The amphipods of the rows as `(node, colour)` pairs, with the rooms numbered from node 11
+pub fn to_board(rows: &[[u8; 4]]) -> Vec<(u8, u8)> {
+    rows.iter()
+        .enumerate()
+        .flat_map(|(row, amphipods)| {
+            amphipods
+                .iter()
+                .enumerate()
+                .map(move |(room, amphipod)| ((11 + row * 4 + room) as u8, *amphipod - b'A'))
+        })
+        .collect()
+}
+
+pub struct Day23;
+
+impl Solution for Day23 {
+    const DAY: usize = 23;
+
+    type Input = Vec<[u8; 4]>;
+    type Part1 = usize;
+    type Part2 = usize;
+
+    fn parse(input: &str) -> Result<Self::Input> {
+        parse(input)
+    }
+
+    fn part1(rows: &Self::Input) -> Result<usize> {
+        // the solver only supports rooms of depth 4, fill the bottom with amphipods that are already in place
+        let board = to_board(&[rows[0], rows[1], *b"ABCD", *b"ABCD"]);
+
+        solve(&board)
+    }
+
+    fn part2(rows: &Self::Input) -> Result<usize> {
+        let board = to_board(&[rows[0], *b"DCBA", *b"DBAC", rows[1]]);
+
+        solve(&board)
+    }
+}
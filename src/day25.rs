@@ -0,0 +1,125 @@
+use crate::cycle::find_cycle;
+use crate::prelude::*;
+
+/// Parses the map of the sea cucumbers, which wraps around at the edges
+pub fn parse(input: &str) -> Result<Grid<char>> {
+    let map = Grid::parse_chars(input)?.wrapping();
+    trace!("Map of {}x{} cells", map.width(), map.height());
+
+    Ok(map)
+}
+
+/// Moves every sea cucumber of a herd which faces a free cell, returns whether any moved
+fn move_herd(map: &Grid<char>, new_map: &mut Grid<char>, herd: char, (dx, dy): (isize, isize)) -> bool {
+    let mut moved = false;
+
+    for ((x, y), curr) in map.iter() {
+        let prev = map.get(x as isize - dx, y as isize - dy).copied();
+        let next = map.get(x as isize + dx, y as isize + dy).copied();
+        let new = if *curr == '.' && prev == Some(herd) {
+            herd
+        } else if *curr == herd && next == Some('.') {
+            '.'
+        } else {
+            *curr
+        };
+        new_map[(x, y)] = new;
+        moved |= new != *curr;
+    }
+
+    moved
+}
+
+/// The sea cucumbers, both herds move once per step
+struct SeaCucumbers {
+    map: Grid<char>,
+    next: Grid<char>,
+}
+
+impl SeaCucumbers {
+    fn new(map: &Grid<char>) -> Self {
+        SeaCucumbers {
+            map: map.clone(),
+            next: map.clone(),
+        }
+    }
+}
+
+impl Simulation for SeaCucumbers {
+    fn step(&mut self) -> bool {
+        let mut moved = move_herd(&self.map, &mut self.next, '>', (1, 0));
+        std::mem::swap(&mut self.map, &mut self.next);
+        moved |= move_herd(&self.map, &mut self.next, 'v', (0, 1));
+        std::mem::swap(&mut self.map, &mut self.next);
+        moved
+    }
+
+    fn render(&self) -> String {
+        self.map.to_string()
+    }
+
+    fn image(&self) -> Option<Image> {
+        Some(pixmap(&self.map))
+    }
+}
+
+fn pixmap(map: &Grid<char>) -> Image {
+    Image::Pixmap(map.map(|cell| match cell {
+        '>' => Rgb::RED,
+        'v' => Rgb::BLUE,
+        _ => Rgb::BLACK,
+    }))
+}
+
+/// Moves both herds once
+pub fn step(map: &Grid<char>) -> Grid<char> {
+    let mut herds = SeaCucumbers::new(map);
+    herds.step();
+    herds.map
+}
+
+/// Number of steps until no sea cucumber moves anymore, and the map they stop in
+///
+/// The step without movement is counted as well. Herds which keep wrapping around forever are an error.
+pub fn steps_until_stopped(map: &Grid<char>) -> Result<(usize, Grid<char>)> {
+    let cycle = find_cycle(map.clone(), step);
+    if !cycle.is_fixed_point() {
+        return Err(Error::General(format!(
+            "The sea cucumbers never stop, they repeat every {} steps after step {}",
+            cycle.period, cycle.start
+        )));
+    }
+
+    Ok((cycle.start + 1, cycle.state))
+}
+
+pub struct Day25;
+
+impl Solution for Day25 {
+    const DAY: usize = 25;
+    const PARTS: usize = 1;
+
+    type Input = Grid<char>;
+    type Part1 = usize;
+    type Part2 = usize;
+
+    fn parse(input: &str) -> Result<Self::Input> {
+        parse(input)
+    }
+
+    fn part1(map: &Self::Input) -> Result<usize> {
+        Ok(steps_until_stopped(map)?.0)
+    }
+
+    fn part2(_: &Self::Input) -> Result<usize> {
+        Err(Error::General("Day 25 has no second part".into()))
+    }
+
+    fn render(map: &Self::Input, _part: usize) -> Result<Option<Image>> {
+        Ok(Some(pixmap(&steps_until_stopped(map)?.1)))
+    }
+
+    fn simulation(map: &Self::Input) -> Option<Box<dyn Simulation>> {
+        Some(Box::new(SeaCucumbers::new(map)))
+    }
+}
@@ -0,0 +1,326 @@
+use crate::parse::Block;
+use crate::prelude::*;
+
+/// Which bit to pick when zeros and ones are equally common
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum TieBreak {
+    Zero,
+    One,
+}
+
+impl TieBreak {
+    fn bit(self) -> bool {
+        self == TieBreak::One
+    }
+
+    fn flip(self) -> Self {
+        match self {
+            TieBreak::Zero => TieBreak::One,
+            TieBreak::One => TieBreak::Zero,
+        }
+    }
+}
+
+/// Whether to follow the more or the less common bit
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Criterion {
+    MostCommon,
+    /// The less common bit among the ones which occur at all
+    LeastCommon,
+}
+
+impl Criterion {
+    /// The bit to pick given how often zeros and ones occur, `None` if neither does
+    fn pick(self, zeros: usize, ones: usize, tie: TieBreak) -> Option<bool> {
+        match (self, zeros, ones) {
+            (_, 0, 0) => None,
+            (Criterion::LeastCommon, 0, _) => Some(true),
+            (Criterion::LeastCommon, _, 0) => Some(false),
+            _ if zeros == ones => Some(tie.bit()),
+            (Criterion::MostCommon, _, _) => Some(ones > zeros),
+            (Criterion::LeastCommon, _, _) => Some(ones < zeros),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, Default)]
+struct Node {
+    /// Number of reports passing through this node
+    count: usize,
+    /// Index of the node for the next bit being 0 or 1, 0 if no report continues that way
+    children: [usize; 2],
+    parent: usize,
+    /// The last bit of the prefix of this node
+    bit: bool,
+}
+
+/// The nodes with the most reports at one depth, the lowest and the highest of them if there is a tie
+#[derive(Debug, Clone, Copy, Default)]
+struct Best {
+    count: usize,
+    lowest: usize,
+    highest: usize,
+}
+
+/// The diagnostic report as a binary trie of bit strings of the same width
+///
+/// Every node counts the reports starting with the bits on its path, so that prefix queries take O(width).
+#[derive(Debug, Clone)]
+pub struct DiagnosticTrie {
+    width: usize,
+    nodes: Vec<Node>,
+    /// Number of reports with a one at each position
+    ones: Vec<usize>,
+    /// The most common prefixes of each length, kept up to date by `insert` since counts only grow
+    best: Vec<Best>,
+}
+
+impl DiagnosticTrie {
+    pub fn new(width: usize) -> Self {
+        DiagnosticTrie {
+            width,
+            nodes: vec![Node::default()],
+            ones: vec![0; width],
+            best: vec![Best::default(); width + 1],
+        }
+    }
+
+    pub fn width(&self) -> usize {
+        self.width
+    }
+
+    /// Number of reports
+    pub fn len(&self) -> usize {
+        self.nodes[0].count
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    pub fn insert(&mut self, bits: &[bool]) -> Result<()> {
+        if bits.len() != self.width {
+            return Err(Error::General(format!("Expected {} bits, got {}", self.width, bits.len())));
+        }
+
+        let mut node = 0;
+        self.nodes[node].count += 1;
+        self.update_best(0, node, &[]);
+        for (i, bit) in bits.iter().enumerate() {
+            self.ones[i] += *bit as usize;
+            node = match self.nodes[node].children[*bit as usize] {
+                0 => {
+                    self.nodes.push(Node {
+                        parent: node,
+                        bit: *bit,
+                        ..Node::default()
+                    });
+                    let child = self.nodes.len() - 1;
+                    self.nodes[node].children[*bit as usize] = child;
+                    child
+                }
+                child => child,
+            };
+            self.nodes[node].count += 1;
+            self.update_best(i + 1, node, &bits[..=i]);
+        }
+
+        Ok(())
+    }
+
+    /// Records that the count of `node` with the given `prefix` went up by one
+    fn update_best(&mut self, depth: usize, node: usize, prefix: &[bool]) {
+        let count = self.nodes[node].count;
+        let best = self.best[depth];
+        if count > best.count {
+            self.best[depth] = Best { count, lowest: node, highest: node };
+        } else if count == best.count {
+            // this node was below the best count before, so it ties with other nodes
+            if prefix < &self.prefix(best.lowest)[..] {
+                self.best[depth].lowest = node;
+            }
+            if prefix > &self.prefix(best.highest)[..] {
+                self.best[depth].highest = node;
+            }
+        }
+    }
+
+    /// The bits on the path to `node`
+    fn prefix(&self, mut node: usize) -> Vec<bool> {
+        let mut bits = vec![];
+        while node != 0 {
+            bits.push(self.nodes[node].bit);
+            node = self.nodes[node].parent;
+        }
+        bits.reverse();
+        bits
+    }
+
+    /// Number of reports starting with `prefix`
+    pub fn count_prefix(&self, prefix: &[bool]) -> usize {
+        let mut node = 0;
+        for bit in prefix {
+            node = match self.nodes[node].children[*bit as usize] {
+                0 => return 0,
+                child => child,
+            };
+        }
+        self.nodes[node].count
+    }
+
+    /// Follows the bits chosen by `criterion` among the remaining reports for `length` bits
+    ///
+    /// This narrows down the reports like the bit criteria of the life support ratings, `None` without reports.
+    pub fn select(&self, length: usize, criterion: Criterion, tie: TieBreak) -> Option<Vec<bool>> {
+        let count = |child: usize| if child == 0 { 0 } else { self.nodes[child].count };
+
+        let mut node = 0;
+        let mut bits = Vec::with_capacity(length.min(self.width));
+        for _ in 0..length.min(self.width) {
+            let [zero, one] = self.nodes[node].children;
+            let bit = criterion.pick(count(zero), count(one), tie)?;
+            bits.push(bit);
+            node = self.nodes[node].children[bit as usize];
+        }
+        Some(bits)
+    }
+
+    /// The prefix of `length` bits shared by the most reports, on ties the lowest one or with [`TieBreak::One`] the
+    /// highest one, `None` without reports
+    ///
+    /// Unlike [`DiagnosticTrie::select`] this compares all prefixes of that length, which were ranked while inserting.
+    pub fn most_common_prefix(&self, length: usize, tie: TieBreak) -> Option<Vec<bool>> {
+        let best = self.best[length.min(self.width)];
+        match (best.count, tie) {
+            (0, _) => None,
+            (_, TieBreak::Zero) => Some(self.prefix(best.lowest)),
+            (_, TieBreak::One) => Some(self.prefix(best.highest)),
+        }
+    }
+
+    /// The most common bit at each position, across all reports
+    pub fn gamma(&self, tie: TieBreak) -> Vec<bool> {
+        self.ones
+            .iter()
+            .map(|ones| Criterion::MostCommon.pick(self.len() - ones, *ones, tie).unwrap_or_else(|| tie.bit()))
+            .collect()
+    }
+
+    /// The least common bit at each position, across all reports, so the complement of [`DiagnosticTrie::gamma`]
+    pub fn epsilon(&self, tie: TieBreak) -> Vec<bool> {
+        self.gamma(tie.flip()).into_iter().map(|bit| !bit).collect()
+    }
+
+    /// The report left by keeping the most common bits, ones on ties
+    pub fn oxygen_generator_rating(&self) -> Option<Vec<bool>> {
+        self.select(self.width, Criterion::MostCommon, TieBreak::One)
+    }
+
+    /// The report left by keeping the least common bits, zeros on ties
+    pub fn co2_scrubber_rating(&self) -> Option<Vec<bool>> {
+        self.select(self.width, Criterion::LeastCommon, TieBreak::Zero)
+    }
+}
+
+/// The value of a bit string, most significant bit first
+pub fn value(bits: &[bool]) -> Result<u128> {
+    if bits.len() > 128 {
+        return Err(Error::General(format!("{} bits do not fit into a number", bits.len())));
+    }
+    Ok(bits.iter().fold(0, |value, bit| value << 1 | *bit as u128))
+}
+
+/// The product of two ratings, which overflows for reports of more than 64 bits with high bits set
+fn product(a: u128, b: u128) -> Result<u128> {
+    a.checked_mul(b).ok_or_else(|| Error::General(format!("{} * {} does not fit into a number", a, b)))
+}
+
+/// Parses one bit string per line, all of the same width
+pub fn parse(input: &str) -> Result<DiagnosticTrie> {
+    let block = Block::new(input);
+    let width = block.lines().next().map_or(0, |(_, line)| line.len());
+    let mut trie = DiagnosticTrie::new(width);
+    block.map_lines(|line| {
+        let bits = line
+            .chars()
+            .enumerate()
+            .map(|(i, ch)| match ch {
+                '0' => Ok(false),
+                '1' => Ok(true),
+                _ => Err(Error::General(format!("Unexpected bit {}", ch))).at_column(i + 1),
+            })
+            .collect::<Result<Vec<_>>>()?;
+        trie.insert(&bits)
+    })?;
+
+    Ok(trie)
+}
+
+pub struct Day3;
+
+impl Solution for Day3 {
+    const DAY: usize = 3;
+
+    type Input = DiagnosticTrie;
+    type Part1 = u128;
+    type Part2 = u128;
+
+    fn parse(input: &str) -> Result<Self::Input> {
+        parse(input)
+    }
+
+    fn part1(trie: &Self::Input) -> Result<u128> {
+        let gamma = value(&trie.gamma(TieBreak::One))?;
+        let epsilon = value(&trie.epsilon(TieBreak::Zero))?;
+
+        product(gamma, epsilon)
+    }
+
+    fn part2(trie: &Self::Input) -> Result<u128> {
+        let oxygen = value(&trie.oxygen_generator_rating().ok_or(Error::EmptyIterator)?)?;
+        let co2 = value(&trie.co2_scrubber_rating().ok_or(Error::EmptyIterator)?)?;
+
+        product(oxygen, co2)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_trie() {
+        let trie = parse("101\n100\n011\n").unwrap();
+        let bits = |s: &str| s.bytes().map(|b| b == b'1').collect::<Vec<_>>();
+        assert_eq!((trie.len(), trie.count_prefix(&bits("10")), trie.count_prefix(&bits("11"))), (3, 2, 0));
+
+        // an odd number of reports has no ties, the majority of 3 is 2
+        assert_eq!(trie.gamma(TieBreak::Zero), bits("101"));
+        assert_eq!(trie.epsilon(TieBreak::Zero), bits("010"));
+        assert_eq!(trie.most_common_prefix(2, TieBreak::Zero), Some(bits("10")));
+        assert_eq!(trie.oxygen_generator_rating(), Some(bits("101")));
+        assert_eq!(trie.co2_scrubber_rating(), Some(bits("011")));
+
+        // a column where every report has a one is all zeros in epsilon
+        let unanimous = parse("110\n100\n101\n").unwrap();
+        assert_eq!((unanimous.gamma(TieBreak::One), unanimous.epsilon(TieBreak::Zero)), (bits("100"), bits("011")));
+
+        // 4 reports start with 0, but 3 share the prefix 10
+        let prefixes = parse("00\n00\n01\n01\n10\n10\n10\n").unwrap();
+        assert_eq!(prefixes.select(2, Criterion::MostCommon, TieBreak::Zero), Some(bits("00")));
+        assert_eq!(prefixes.most_common_prefix(2, TieBreak::Zero), Some(bits("10")));
+        assert_eq!(prefixes.most_common_prefix(1, TieBreak::Zero), Some(bits("0")));
+        assert_eq!(prefixes.most_common_prefix(0, TieBreak::Zero), Some(vec![]));
+        assert_eq!(parse("").unwrap().most_common_prefix(2, TieBreak::Zero), None);
+        let tied = parse("01\n10\n").unwrap();
+        assert_eq!(tied.most_common_prefix(2, TieBreak::Zero), Some(bits("01")));
+        assert_eq!(tied.most_common_prefix(2, TieBreak::One), Some(bits("10")));
+
+        let wide = parse(&format!("{}\n", "1".repeat(100))).unwrap();
+        assert_eq!((wide.width(), wide.gamma(TieBreak::Zero).len()), (100, 100));
+        assert_eq!(value(&wide.gamma(TieBreak::Zero)).unwrap(), (1 << 100) - 1);
+        assert_eq!(Day3::part1(&wide).unwrap(), 0);
+        assert!(value(&[true; 129]).is_err());
+        assert_eq!(parse("101\n10\n").unwrap_err().to_string(), "line 2: General: Expected 3 bits, got 2 in \"10\"");
+    }
+}
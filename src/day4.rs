@@ -0,0 +1,467 @@
+use crate::parse::{comma_separated, whitespace_separated, Block};
+use crate::prelude::*;
+use std::collections::{HashMap, HashSet};
+use std::fmt::{Display, Formatter};
+
+/// A square bingo board
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Board {
+    numbers: Grid<u32>,
+}
+
+impl Board {
+    /// Builds a board from its rows, which need as many numbers as there are rows
+    pub fn new(rows: Vec<Vec<u32>>) -> Result<Self> {
+        let size = rows.len();
+        if let Some(row) = rows.iter().find(|row| row.len() != size) {
+            return Err(Error::General(format!("Expected {} numbers per row, got {}", size, row.len())));
+        }
+        let numbers = Grid::from_vec(size, rows.into_iter().flatten().collect())?;
+        Ok(Board { numbers })
+    }
+
+    /// Number of rows and columns
+    pub fn size(&self) -> usize {
+        self.numbers.width()
+    }
+
+    pub fn numbers(&self) -> &Grid<u32> {
+        &self.numbers
+    }
+
+    /// Positions of `number` on the board
+    pub fn find(&self, number: u32) -> impl Iterator<Item = (usize, usize)> + '_ {
+        self.numbers.iter().filter(move |(_, n)| **n == number).map(|(position, _)| position)
+    }
+
+    /// Numbers of the lines that win: rows, columns and with `diagonals` both diagonals
+    pub fn lines(&self, diagonals: bool) -> Vec<Vec<u32>> {
+        let size = self.size();
+        let mut lines = self.numbers.rows().map(<[u32]>::to_vec).collect::<Vec<_>>();
+        lines.extend(self.numbers.columns().map(|column| column.copied().collect()));
+        if diagonals && size > 0 {
+            lines.push((0..size).map(|i| self.numbers[(i, i)]).collect());
+            lines.push((0..size).map(|i| self.numbers[(size - 1 - i, i)]).collect());
+        }
+        lines
+    }
+}
+
+/// Rows right aligned like the puzzle input
+impl Display for Board {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        let width = self.numbers.cells().iter().map(|n| n.to_string().len()).max().unwrap_or(0);
+        for row in self.numbers.rows() {
+            let row = row.iter().map(|n| format!("{:>1$}", n, width)).collect::<Vec<_>>();
+            writeln!(f, "{}", row.join(" "))?;
+        }
+        Ok(())
+    }
+}
+
+/// The marks on a board during a game
+///
+/// Every line counts its marks, so a number is marked and checked in O(size).
+#[derive(Debug, Clone)]
+pub struct Card {
+    marked: Grid<bool>,
+    rows: Vec<usize>,
+    columns: Vec<usize>,
+    diagonals: [usize; 2],
+    unmarked: u64,
+}
+
+impl Card {
+    pub fn new(board: &Board) -> Self {
+        let size = board.size();
+        Card {
+            marked: Grid::new(size, size, false),
+            rows: vec![0; size],
+            columns: vec![0; size],
+            diagonals: [0; 2],
+            unmarked: board.numbers.cells().iter().map(|n| *n as u64).sum(),
+        }
+    }
+
+    /// Marks `number`, returning whether that completes a line, diagonals only count with `diagonals`
+    pub fn mark(&mut self, board: &Board, number: u32, diagonals: bool) -> bool {
+        let size = board.size();
+        let mut won = false;
+        for (x, y) in board.find(number) {
+            let marked = &mut self.marked[(x, y)];
+            if *marked {
+                continue;
+            }
+            *marked = true;
+            self.unmarked -= number as u64;
+            self.rows[y] += 1;
+            self.columns[x] += 1;
+            won |= self.rows[y] == size || self.columns[x] == size;
+            if x == y {
+                self.diagonals[0] += 1;
+                won |= diagonals && self.diagonals[0] == size;
+            }
+            if x + y + 1 == size {
+                self.diagonals[1] += 1;
+                won |= diagonals && self.diagonals[1] == size;
+            }
+        }
+        won
+    }
+
+    /// Sum of the numbers not marked yet
+    pub fn unmarked(&self) -> u64 {
+        self.unmarked
+    }
+}
+
+/// A board completing a line
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Win {
+    /// Index of the board in the input
+    pub board: usize,
+    /// Number of draws up to and including the winning one
+    pub turn: usize,
+    pub number: u32,
+    /// The winning number times the sum of the unmarked numbers
+    pub score: u64,
+}
+
+impl Display for Win {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "board {} wins on turn {} with {}, score {}",
+            self.board, self.turn, self.number, self.score
+        )
+    }
+}
+
+/// When the target board of [`Bingo::rigged_draws`] should win
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Goal {
+    /// Before any other board
+    First,
+    /// After all other boards
+    Last,
+}
+
+/// Plays bingo on several boards, which leave the game once they win
+#[derive(Debug, Clone)]
+pub struct Bingo {
+    boards: Vec<Board>,
+    diagonals: bool,
+    rig_limit: usize,
+}
+
+impl Bingo {
+    pub fn new(boards: Vec<Board>) -> Self {
+        Bingo {
+            boards,
+            diagonals: false,
+            rig_limit: RIG_LIMIT,
+        }
+    }
+
+    /// Whether completed diagonals win as well
+    pub fn diagonals(mut self, diagonals: bool) -> Self {
+        self.diagonals = diagonals;
+        self
+    }
+
+    /// Number of sets of drawn numbers [`Bingo::rigged_draws`] looks at for [`Goal::Last`], see [`RIG_LIMIT`]
+    pub fn rig_limit(mut self, rig_limit: usize) -> Self {
+        self.rig_limit = rig_limit;
+        self
+    }
+
+    pub fn boards(&self) -> &[Board] {
+        &self.boards
+    }
+
+    /// Draws the numbers in order, reporting every board which wins in the order of winning
+    ///
+    /// Boards winning on the same draw are reported in input order, boards never winning are left out.
+    pub fn play(&self, draws: &[u32]) -> Vec<Win> {
+        let mut active = self.boards.iter().map(Card::new).enumerate().collect::<Vec<_>>();
+        let mut wins = vec![];
+
+        for (turn, number) in draws.iter().copied().enumerate() {
+            let mut i = 0;
+            while i < active.len() {
+                let (index, card) = &mut active[i];
+                if card.mark(&self.boards[*index], number, self.diagonals) {
+                    wins.push(Win {
+                        board: *index,
+                        turn: turn + 1,
+                        number,
+                        score: number as u64 * card.unmarked(),
+                    });
+                    active.remove(i);
+                } else {
+                    i += 1;
+                }
+            }
+            if active.is_empty() {
+                break;
+            }
+        }
+
+        wins
+    }
+
+    /// The shortest draws under which board `target` wins strictly before or after all other boards
+    ///
+    /// Winning first draws a single line of the target. Winning last searches the line every other board wins with,
+    /// up to [`Bingo::rig_limit`] sets of drawn numbers. Fails for a `target` out of range or when the target can never
+    /// win that way.
+    pub fn rigged_draws(&self, target: usize, goal: Goal) -> Result<Vec<u32>> {
+        if target >= self.boards.len() {
+            return Err(Error::General(format!("No board {}, there are {}", target, self.boards.len())));
+        }
+        let lines = self.boards.iter().map(|board| board.lines(self.diagonals)).collect::<Vec<_>>();
+
+        match goal {
+            // drawing just one line of the target wins fastest, as long as that completes no other board
+            Goal::First => {
+                let completes_other = |line: &Vec<u32>| {
+                    let others = lines.iter().enumerate().filter(|(i, _)| *i != target);
+                    others.flat_map(|(_, lines)| lines).any(|other| other.iter().all(|n| line.contains(n)))
+                };
+                let line = lines[target].iter().find(|line| !completes_other(line));
+                line.cloned().ok_or_else(|| Error::General(format!("Board {} can never win first", target)))
+            }
+            Goal::Last => {
+                // the search works on sets of indices into the distinct numbers
+                let numbers = self.boards.iter().flat_map(|board| board.numbers.cells());
+                let mut numbers = numbers.copied().collect::<Vec<_>>();
+                numbers.sort_unstable();
+                numbers.dedup();
+                let index = numbers.iter().enumerate().map(|(i, n)| (*n, i)).collect::<HashMap<_, _>>();
+                let lines = lines
+                    .iter()
+                    .map(|lines| lines.iter().map(|line| line.iter().map(|n| index[n]).collect()).collect())
+                    .collect::<Vec<Vec<BitSet>>>();
+                let others = lines.iter().enumerate().filter(|(i, _)| *i != target).map(|(_, lines)| lines.clone());
+                let others = others.collect::<Vec<_>>();
+
+                let mut search = LastSearch {
+                    targets: &lines[target],
+                    others: &others,
+                    limit: self.rig_limit,
+                    visited: HashSet::new(),
+                    gave_up: false,
+                    best: None,
+                };
+                search.run(&BitSet::default(), &mut vec![]);
+                info!("Searched {} states for board {} to win last", search.visited.len(), target);
+
+                match search.best {
+                    Some(best) => {
+                        if search.gave_up {
+                            info!("Gave up after {} states, the draws may not be the shortest", self.rig_limit);
+                        }
+                        Ok(best.into_iter().map(|i| numbers[i]).collect())
+                    }
+                    None if search.gave_up => Err(Error::General(format!(
+                        "Gave up on board {} winning last after {} states",
+                        target, self.rig_limit
+                    ))),
+                    None => Err(Error::General(format!("Board {} can never win last", target))),
+                }
+            }
+        }
+    }
+}
+
+/// Number of sets of drawn numbers [`Bingo::rigged_draws`] looks at for [`Goal::Last`] before it settles for the
+/// shortest draws found so far, which finishes the search on small inputs and takes about a second for the puzzle input
+pub const RIG_LIMIT: usize = 20_000;
+
+/// Depth first branch and bound over the line each other board wins with, for [`Goal::Last`]
+///
+/// The first lines tried are the ones needing the fewest new numbers, so the first draws found are already short.
+struct LastSearch<'a> {
+    targets: &'a [BitSet],
+    others: &'a [Vec<BitSet>],
+    limit: usize,
+    /// The sets of drawn numbers searched already, the rest of the search only depends on them
+    visited: HashSet<BitSet>,
+    gave_up: bool,
+    /// The shortest draws found so far
+    best: Option<Vec<usize>>,
+}
+
+impl<'a> LastSearch<'a> {
+    fn run(&mut self, drawn: &BitSet, draws: &mut Vec<usize>) {
+        if self.visited.len() >= self.limit {
+            self.gave_up = true;
+            return;
+        }
+        if !self.visited.insert(drawn.clone()) {
+            return;
+        }
+
+        let missing = |line: &BitSet| line.and_not(drawn).len();
+        let targets = self.targets;
+        // a line is only drawn if it completes none of these
+        let open_targets = targets.iter().map(|target| target.and_not(drawn)).collect::<Vec<_>>();
+
+        // branch on the board with the fewest lines left to win with, all of them need at least `bound` numbers
+        let mut bound = 0;
+        let mut branch: Option<Vec<&BitSet>> = None;
+        for lines in self.others {
+            if lines.iter().any(|line| line.is_subset(drawn)) {
+                continue;
+            }
+            let valid = lines
+                .iter()
+                .filter(|line| !open_targets.iter().any(|target| target.is_subset(line)))
+                .collect::<Vec<_>>();
+            match valid.iter().map(|line| missing(line)).min() {
+                None => return,
+                Some(missing) => bound = bound.max(missing),
+            }
+            if branch.as_ref().map_or(true, |branch| valid.len() < branch.len()) {
+                branch = Some(valid);
+            }
+        }
+
+        let mut branch = match branch {
+            // every other board has won, so the target line missing the fewest numbers completes last
+            None => {
+                if let Some(line) = targets.iter().min_by_key(|line| missing(line)) {
+                    if self.best.as_ref().map_or(true, |best| draws.len() + missing(line) < best.len()) {
+                        self.best = Some(draws.iter().copied().chain(line.and_not(drawn).iter()).collect());
+                    }
+                }
+                return;
+            }
+            Some(branch) => branch,
+        };
+        // the target needs at least one more number after the others
+        if self.best.as_ref().map_or(false, |best| draws.len() + bound + 1 >= best.len()) {
+            return;
+        }
+
+        branch.sort_by_key(|line| missing(line));
+        for line in branch {
+            let len = draws.len();
+            draws.extend(line.and_not(drawn).iter());
+            self.run(&drawn.or(line), draws);
+            draws.truncate(len);
+        }
+    }
+}
+
+fn read_board(block: Block) -> Result<Board> {
+    let rows = block.map_lines(whitespace_separated::<u32>)?;
+    let (n, line) = block.lines().last().ok_or(Error::EmptyIterator)?;
+    Board::new(rows).at_line(n, line)
+}
+
+/// Parses the drawn numbers and the boards
+pub fn parse(input: &str) -> Result<(Vec<u32>, Bingo)> {
+    let (numbers, boards) = Block::new(input).header_body()?;
+    let numbers = numbers.single_line(comma_separated)?;
+    let boards = boards.blocks().into_iter().map(read_board).collect::<Result<Vec<_>>>()?;
+
+    Ok((numbers, Bingo::new(boards)))
+}
+
+pub struct Day4;
+
+impl Solution for Day4 {
+    const DAY: usize = 4;
+
+    type Input = (Vec<u32>, Bingo);
+    type Part1 = u64;
+    type Part2 = u64;
+
+    fn parse(input: &str) -> Result<Self::Input> {
+        parse(input)
+    }
+
+    fn part1((numbers, bingo): &Self::Input) -> Result<u64> {
+        let win = bingo.play(numbers).first().copied().ok_or(Error::EmptyIterator)?;
+        debug!("First win: {}", win);
+        Ok(win.score)
+    }
+
+    fn part2((numbers, bingo): &Self::Input) -> Result<u64> {
+        let win = bingo.play(numbers).last().copied().ok_or(Error::EmptyIterator)?;
+        debug!("Last win: {}", win);
+        Ok(win.score)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_bingo() {
+        let (draws, bingo) = parse("5,1,9,4,7\n\n1 2 3\n4 5 6\n7 8 9\n\n9 5\n7 3\n").unwrap();
+        let wins = bingo.play(&draws);
+        assert_eq!(
+            wins,
+            vec![Win { board: 1, turn: 3, number: 9, score: 9 * 10 }, Win { board: 0, turn: 5, number: 7, score: 7 * 19 }]
+        );
+
+        // the diagonal 1 5 9 completes on the third draw
+        let wins = bingo.diagonals(true).play(&draws);
+        assert_eq!(wins[0], Win { board: 0, turn: 3, number: 9, score: 9 * 30 });
+
+        assert_eq!(
+            parse("1\n\n1 2\n3\n").unwrap_err().to_string(),
+            "line 4: General: Expected 2 numbers per row, got 1 in \"3\""
+        );
+    }
+
+    #[test]
+    fn test_rigged_draws() {
+        let check = |bingo: &Bingo, target: usize, goal: Goal| {
+            let draws = bingo.rigged_draws(target, goal).unwrap();
+            let wins = bingo.play(&draws);
+            let (first, last) = (wins[0], wins[wins.len() - 1]);
+            match goal {
+                Goal::First => {
+                    assert_eq!((first.board, first.turn), (target, draws.len()));
+                    assert!(wins.get(1).map_or(true, |win| win.turn > draws.len()));
+                }
+                Goal::Last => {
+                    assert_eq!((wins.len(), last.board, last.turn), (bingo.boards().len(), target, draws.len()));
+                    assert!(wins[wins.len() - 2].turn < draws.len());
+                }
+            }
+            draws.len()
+        };
+
+        // small enough to search completely, so the draws are the shortest
+        let example = parse(include_str!("../data/a4_example.txt")).unwrap().1;
+        for (bingo, last) in [(example.clone(), [8, 9, 8]), (example.diagonals(true), [8, 8, 8])] {
+            for (target, last) in last.into_iter().enumerate() {
+                assert_eq!((check(&bingo, target, Goal::First), check(&bingo, target, Goal::Last)), (5, last));
+            }
+        }
+
+        let input = parse(include_str!("../data/a4_input.txt")).unwrap().1.rig_limit(1000);
+        for target in 0..input.boards().len() {
+            assert_eq!(check(&input, target, Goal::First), 5);
+        }
+        check(&input, 0, Goal::Last);
+
+        // drawing a line of the second board first takes a number more than the 5 7 both others share
+        let (_, shared) = parse("1\n\n1 2\n3 4\n\n5 6\n7 8\n\n5 9\n7 10\n").unwrap();
+        assert_eq!(shared.rigged_draws(0, Goal::Last).unwrap(), vec![5, 7, 1, 2]);
+        // the lines of the second board with a 1 complete the target together with the 2 of the last board
+        let (_, forced) = parse("1\n\n1 2\n3 4\n\n1 5\n6 3\n\n2\n").unwrap();
+        assert_eq!(check(&forced, 0, Goal::Last), 4);
+
+        let error = |bingo: &Bingo, target, goal| bingo.rigged_draws(target, goal).unwrap_err().to_string();
+        let (_, twins) = parse("1\n\n1 2\n3 4\n\n1 2\n3 4\n").unwrap();
+        assert_eq!(error(&twins, 0, Goal::First), "General: Board 0 can never win first");
+        assert_eq!(error(&twins, 1, Goal::Last), "General: Board 1 can never win last");
+        assert_eq!(error(&twins, 2, Goal::Last), "General: No board 2, there are 2");
+    }
+}
@@ -0,0 +1,76 @@
+use crate::parse::Block;
+use crate::prelude::*;
+use crate::sparse::Position;
+
+type Point = Position;
+
+/// Counts how many lines cover each point
+pub fn overlaps(lines: &[(Point, Point)], only_horz_or_vert: bool) -> SparseGrid<usize> {
+    let mut map = SparseGrid::new(0_usize);
+
+    for (start, end) in lines.iter().copied() {
+        let step = (end - start).signum();
+        if !only_horz_or_vert || (step.x == 0 || step.y == 0) {
+            for p in start.steps_to(end) {
+                map.update(p, |count| *count += 1);
+            }
+        }
+    }
+
+    map
+}
+
+/// Number of points where at least two lines overlap
+pub fn solve(lines: &[(Point, Point)], only_horz_or_vert: bool) -> usize {
+    let map = overlaps(lines, only_horz_or_vert);
+
+    trace!("\n{}", map.to_grid().map(|count| if *count == 0 { ".".to_string() } else { count.to_string() }));
+
+    map.iter().filter(|(_, c)| **c >= 2).count()
+}
+
+/// Parses one line segment per line, like `0,9 -> 5,9`
+pub fn parse(input: &str) -> Result<Vec<(Point, Point)>> {
+    let pattern = regex!(r"^(\d+,\d+) *-> *(\d+,\d+)$");
+
+    let lines = Block::new(input).records(pattern, |captures| {
+        let start: Point = parse_capture(captures, 1)?;
+        let end: Point = parse_capture(captures, 2)?;
+        if end.x < start.x {
+            Ok((end, start))
+        } else {
+            Ok((start, end))
+        }
+    })?;
+
+    Ok(lines)
+}
+
+pub struct Day5;
+
+impl Solution for Day5 {
+    const DAY: usize = 5;
+
+    type Input = Vec<(Point, Point)>;
+    type Part1 = usize;
+    type Part2 = usize;
+
+    fn parse(input: &str) -> Result<Self::Input> {
+        parse(input)
+    }
+
+    fn part1(lines: &Self::Input) -> Result<usize> {
+        Ok(solve(lines, true))
+    }
+
+    fn part2(lines: &Self::Input) -> Result<usize> {
+        Ok(solve(lines, false))
+    }
+
+    fn render(lines: &Self::Input, part: usize) -> Result<Option<Image>> {
+        let map = overlaps(lines, part == 1).to_grid();
+        let max = map.cells().iter().copied().max().unwrap_or(0);
+
+        Ok(Some(ColourMap::heat(0.0, max as f64).apply(&map, |count| *count as f64)))
+    }
+}
@@ -0,0 +1,120 @@
+use crate::parse::{comma_separated, Block};
+use crate::cycle::state_after;
+use crate::prelude::*;
+
+/// Parses the ages of the fish
+pub fn parse(input: &str) -> Result<Vec<u32>> {
+    Block::new(input).single_line(comma_separated)
+}
+
+/// Number of fish after `days` days, simulating every fish on its own
+pub fn simulate(ages: &[u32], days: i32) -> usize {
+    let mut ages = ages.to_vec();
+    let mut new_ages = Vec::with_capacity(4096);
+
+    for _day in 0..days {
+        new_ages.clear();
+        for age in ages.iter() {
+            if let Some(age) = age.checked_sub(1) {
+                new_ages.push(age);
+            } else {
+                new_ages.push(6);
+                new_ages.push(8);
+            }
+        }
+
+        std::mem::swap(&mut ages, &mut new_ages)
+    }
+
+    ages.len()
+}
+
+/// Counts the fish per age
+pub fn histogram(ages: &[u32]) -> [usize; 9] {
+    let mut histogram = [0_usize; 9];
+    for age in ages.iter() {
+        unsafe { std::intrinsics::assume((*age as usize) < histogram.len()); }
+        histogram[*age as usize] += 1;
+    }
+    histogram
+}
+
+/// Moves all counters of the histogram down by one age every day with `step`
+pub fn simulate_histogram_with(ages: &[u32], days: usize, step: impl Fn(&mut [usize; 9])) -> usize {
+    let mut histogram = histogram(ages);
+
+    for _day in 0..days {
+        step(&mut histogram);
+    }
+
+    histogram.iter().sum()
+}
+
+fn step_ptr_copy(histogram: &mut [usize; 9]) {
+    let count = histogram[0];
+    unsafe {
+        std::ptr::copy(histogram.as_ptr().add(1), histogram.as_mut_ptr(), 8)
+    }
+    histogram[6] += count;
+    histogram[8] = count;
+}
+
+fn step_shift(histogram: &mut [usize; 9]) {
+    let count = histogram[0];
+    for i in 1..histogram.len() {
+        histogram[i - 1] = histogram[i]
+    }
+    histogram[6] += count;
+    histogram[8] = count;
+}
+
+fn step_rotate(histogram: &mut [usize; 9]) {
+    histogram.rotate_left(1);
+    histogram[6] += histogram[8];
+}
+
+/// Steps the histogram with [`state_after`], which would skip ahead if the fish ever came back to an earlier state
+fn simulate_histogram_state_after(ages: &[u32], days: usize) -> usize {
+    let step = |histogram: &[usize; 9]| {
+        let mut histogram = *histogram;
+        step_rotate(&mut histogram);
+        histogram
+    };
+
+    state_after(histogram(ages), step, days).iter().sum()
+}
+
+/// Number of fish after `days` days
+pub fn simulate_histogram(ages: &[u32], days: usize) -> usize {
+    simulate_histogram_with(ages, days, step_ptr_copy)
+}
+
+pub struct Day6;
+
+impl Solution for Day6 {
+    const DAY: usize = 6;
+
+    type Input = Vec<u32>;
+    type Part1 = usize;
+    type Part2 = usize;
+
+    fn parse(input: &str) -> Result<Self::Input> {
+        parse(input)
+    }
+
+    fn part1(ages: &Self::Input) -> Result<usize> {
+        Ok(simulate(ages, 80))
+    }
+
+    fn part2(ages: &Self::Input) -> Result<usize> {
+        Ok(simulate_histogram(ages, 256))
+    }
+
+    fn alternatives() -> Vec<Alternative<Self::Input>> {
+        vec![
+            Alternative::new("part2_shift", |ages: &Self::Input| Ok(simulate_histogram_with(ages, 256, step_shift))),
+            Alternative::new("part2_rotate", |ages: &Self::Input| Ok(simulate_histogram_with(ages, 256, step_rotate))),
+            Alternative::new("part2_state_after", |ages: &Self::Input| Ok(simulate_histogram_state_after(ages, 256))),
+        ]
+    }
+}
@@ -0,0 +1,54 @@
+use crate::parse::{comma_separated, Block};
+use crate::prelude::*;
+
+/// Parses the positions of the crabs
+pub fn parse(input: &str) -> Result<Vec<i32>> {
+    Block::new(input).single_line(comma_separated)
+}
+
+pub struct Day7;
+
+impl Solution for Day7 {
+    const DAY: usize = 7;
+
+    type Input = Vec<i32>;
+    type Part1 = u64;
+    type Part2 = u64;
+
+    fn parse(input: &str) -> Result<Self::Input> {
+        parse(input)
+    }
+
+    fn part1(positions: &Self::Input) -> Result<u64> {
+        let min = *positions.iter().min().ok_or(Error::EmptyIterator)?;
+        let max = *positions.iter().max().ok_or(Error::EmptyIterator)?;
+
+        let min_fuel_part1 = (min..max + 1)
+            .map(|pos| positions.iter().map(|n| n.abs_diff(pos) as u64).sum::<u64>())
+            .min()
+            .ok_or(Error::EmptyIterator)?;
+
+        Ok(min_fuel_part1)
+    }
+
+    fn part2(positions: &Self::Input) -> Result<u64> {
+        let min = *positions.iter().min().ok_or(Error::EmptyIterator)?;
+        let max = *positions.iter().max().ok_or(Error::EmptyIterator)?;
+
+        let min_fuel_part2 = (min..max + 1)
+            .map(|pos| {
+                positions
+                    .iter()
+                    .map(|n| {
+                        let diff = n.abs_diff(pos) as u64;
+
+                        diff * (diff + 1) / 2
+                    })
+                    .sum::<u64>()
+            })
+            .min()
+            .ok_or(Error::EmptyIterator)?;
+
+        Ok(min_fuel_part2)
+    }
+}
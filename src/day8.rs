@@ -0,0 +1,132 @@
+use crate::parse::Block;
+use crate::prelude::*;
+
+pub struct Entry {
+    patterns: [u8; 10],
+    output_values: [u8; 4],
+}
+
+fn pattern_to_mask(pattern: &str) -> Result<u8> {
+    pattern.bytes().try_fold(0_u8, |acc, segment| match segment {
+        b'a'..=b'g' => Ok(acc | (1 << (segment - b'a'))),
+        _ => Err(Error::General(format!("Unexpected segment {}", segment as char))),
+    })
+}
+
+fn parse<const N: usize>(input: &str) -> Result<[u8; N]> {
+    let mut result = [0_u8; N];
+    for (i, pattern) in input.split(' ').take(N).enumerate() {
+        result[i] = pattern_to_mask(pattern)?;
+    }
+
+    if result.iter().any(|mask| *mask == 0) {
+        return Err(Error::General("Missing input".into()));
+    }
+
+    Ok(result)
+}
+
+/// Number of output digits which use a unique number of segments, the digits 1, 4, 7 and 8
+pub fn count_unique_segments(lines: &[Entry]) -> usize {
+    lines
+        .iter()
+        .flat_map(|input| input.output_values.iter())
+        .filter(|v| {
+            let segments = v.count_ones();
+            segments == 2 || segments == 4 || segments == 3 || segments == 7
+        })
+        .count()
+}
+
+/// Decodes the output value of an entry from its patterns
+pub fn part2_line(input: &Entry) -> Result<u32> {
+    let patterns = input.patterns;
+    let one = *patterns
+        .iter()
+        .find(|m| m.count_ones() == 2)
+        .ok_or(Error::General("one".into()))?;
+    let seven = *patterns
+        .iter()
+        .find(|m| m.count_ones() == 3)
+        .ok_or(Error::General("seven".into()))?;
+    let eight = *patterns
+        .iter()
+        .find(|m| m.count_ones() == 7)
+        .ok_or(Error::General("eight".into()))?;
+    let four = *patterns
+        .iter()
+        .find(|m| m.count_ones() == 4)
+        .ok_or(Error::General("four".into()))?;
+    let zero = *patterns
+        .iter()
+        .find(|m| m.count_ones() == 6 && (**m & !seven).count_ones() == 3 && (**m & !four).count_ones() == 3)
+        .ok_or(Error::General("zero".into()))?;
+    let three = *patterns
+        .iter()
+        .find(|m| m.count_ones() == 5 && (**m & !seven).count_ones() == 2)
+        .ok_or(Error::General("three".into()))?;
+    let nine = *patterns
+        .iter()
+        .find(|m| m.count_ones() == 6 && (**m & seven).count_ones() == 3 && (**m & !four).count_ones() == 2)
+        .ok_or(Error::General("nine".into()))?;
+    let six = *patterns
+        .iter()
+        .find(|m| m.count_ones() == 6 && (**m & one).count_ones() == 1)
+        .ok_or(Error::General("six".into()))?;
+    let five = *patterns
+        .iter()
+        .find(|m| m.count_ones() == 5 && (**m & one).count_ones() == 1 && (**m & four).count_ones() == 3)
+        .ok_or(Error::General("five".into()))?;
+    let two = *patterns
+        .iter()
+        .find(|m| m.count_ones() == 5 && (**m & one).count_ones() == 1 && (**m & four).count_ones() == 2)
+        .ok_or(Error::General("two".into()))?;
+
+    let numbers = [zero, one, two, three, four, five, six, seven, eight, nine];
+
+    let result = input.output_values.iter().try_fold(0_u32, |a, m| -> Result<u32> {
+        let digit_pos = numbers.iter().position(|n| *n == *m).ok_or(Error::EmptyIterator)?;
+        Ok(a * 10 + digit_pos as u32)
+    })?;
+
+    Ok(result)
+}
+
+/// Parses one entry per line, the ten patterns and the four output values
+pub fn parse_input(input: &str) -> Result<Vec<Entry>> {
+    Block::new(input).map_lines(|line| {
+        let (patterns, output_values) = line.split_once(" | ").ok_or(Error::PatternMatch)?;
+
+        Ok(Entry {
+            patterns: parse(patterns)?,
+            output_values: parse(output_values)?,
+        })
+    })
+}
+
+pub struct Day8;
+
+impl Solution for Day8 {
+    const DAY: usize = 8;
+
+    type Input = Vec<Entry>;
+    type Part1 = usize;
+    type Part2 = u32;
+
+    fn parse(input: &str) -> Result<Self::Input> {
+        parse_input(input)
+    }
+
+    fn part1(lines: &Self::Input) -> Result<usize> {
+        Ok(count_unique_segments(lines))
+    }
+
+    fn part2(lines: &Self::Input) -> Result<u32> {
+        let part2 = lines.iter().try_fold(0_u32, |sum, l| -> Result<u32> {
+            let num = part2_line(l)?;
+            Ok(sum + num)
+        })?;
+
+        Ok(part2)
+    }
+}
@@ -0,0 +1,73 @@
+use crate::prelude::*;
+use crate::grid::NEIGHBOURS4;
+
+fn floodfill(map: &mut Grid<u8>, size: &mut usize, position: (usize, usize)) {
+    let h = &mut map[position];
+    if *h < 9_u8 {
+        *size += 1;
+        *h = 255; // mark as visited
+
+        for delta in NEIGHBOURS4 {
+            if let Some(neighbour) = map.offset(position, delta) {
+                floodfill(map, size, neighbour);
+            }
+        }
+    }
+}
+
+/// Returns the total risk level and the positions of all low points
+pub fn low_points(map: &Grid<u8>) -> (u64, Vec<(usize, usize)>) {
+    let mut risk = 0_u64;
+    let mut basins = vec![];
+    for (position, h) in map.iter() {
+        if map.neighbours4(position).all(|neighbour| map[neighbour] > *h) {
+            risk += *h as u64 + 1;
+            basins.push(position);
+        }
+    }
+    (risk, basins)
+}
+
+pub struct Day9;
+
+impl Solution for Day9 {
+    const DAY: usize = 9;
+
+    type Input = Grid<u8>;
+    type Part1 = u64;
+    type Part2 = usize;
+
+    fn parse(input: &str) -> Result<Self::Input> {
+        Grid::parse_digits(input)
+    }
+
+    fn part1(map: &Self::Input) -> Result<u64> {
+        let (risk, _) = low_points(map);
+
+        Ok(risk)
+    }
+
+    fn part2(map: &Self::Input) -> Result<usize> {
+        let mut map = map.clone();
+        let (_, basins) = low_points(&map);
+
+        let mut sizes = vec![];
+
+        for position in basins {
+            let mut size = 0;
+            floodfill(&mut map, &mut size, position);
+            sizes.push(size);
+        }
+
+        sizes.sort();
+        let part2 = sizes.iter().rev().take(3).product::<usize>();
+
+        Ok(part2)
+    }
+
+    fn render(map: &Self::Input, part: usize) -> Result<Option<Image>> {
+        let colours = ColourMap::new(0.0, 9.0, vec![Rgb::BLUE, Rgb::WHITE]);
+
+        Ok((part == 1).then(|| colours.apply(map, |height| *height as f64)))
+    }
+}
@@ -0,0 +1,177 @@
+use crate::bitset::BitSet;
+use std::collections::{HashMap, VecDeque};
+
+/// A directed graph of named nodes, which are interned to dense ids in the order they are first seen
+///
+/// Every node carries an attribute of type `A`, starting out as its default value.
+#[derive(Debug, Clone)]
+pub struct Graph<A = ()> {
+    ids: HashMap<String, usize>,
+    names: Vec<String>,
+    adjacency: Vec<Vec<usize>>,
+    attributes: Vec<A>,
+}
+
+impl<A> Default for Graph<A> {
+    fn default() -> Self {
+        Graph {
+            ids: HashMap::new(),
+            names: vec![],
+            adjacency: vec![],
+            attributes: vec![],
+        }
+    }
+}
+
+impl<A: Default> Graph<A> {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// The id of the node with the given name, adding the node if it is new
+    pub fn node(&mut self, name: &str) -> usize {
+        if let Some(id) = self.ids.get(name) {
+            return *id;
+        }
+        let id = self.names.len();
+        self.ids.insert(name.to_string(), id);
+        self.names.push(name.to_string());
+        self.adjacency.push(vec![]);
+        self.attributes.push(A::default());
+        id
+    }
+
+    /// Adds an edge from one named node to another, returning both ids
+    pub fn add_edge(&mut self, from: &str, to: &str) -> (usize, usize) {
+        let (from, to) = (self.node(from), self.node(to));
+        self.adjacency[from].push(to);
+        (from, to)
+    }
+
+    /// Adds edges in both directions between two named nodes, returning both ids
+    pub fn add_undirected_edge(&mut self, a: &str, b: &str) -> (usize, usize) {
+        let (a, b) = self.add_edge(a, b);
+        self.adjacency[b].push(a);
+        (a, b)
+    }
+}
+
+impl<A> Graph<A> {
+    pub fn len(&self) -> usize {
+        self.names.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.names.is_empty()
+    }
+
+    pub fn id(&self, name: &str) -> Option<usize> {
+        self.ids.get(name).copied()
+    }
+
+    pub fn name(&self, id: usize) -> &str {
+        &self.names[id]
+    }
+
+    /// All node ids with their names, in id order
+    pub fn nodes(&self) -> impl Iterator<Item = (usize, &str)> {
+        self.names.iter().map(String::as_str).enumerate()
+    }
+
+    /// The targets of the edges leaving a node, in the order the edges were added
+    pub fn neighbours(&self, id: usize) -> &[usize] {
+        &self.adjacency[id]
+    }
+
+    pub fn attribute(&self, id: usize) -> &A {
+        &self.attributes[id]
+    }
+
+    pub fn attribute_mut(&mut self, id: usize) -> &mut A {
+        &mut self.attributes[id]
+    }
+
+    /// Visits the nodes reachable from `start` in breadth first order
+    pub fn bfs(&self, start: usize) -> Bfs<A> {
+        Bfs {
+            graph: self,
+            queue: VecDeque::from([start]),
+            seen: BitSet::default().set(start),
+        }
+    }
+
+    /// Visits the nodes reachable from `start` in depth first pre-order
+    pub fn dfs(&self, start: usize) -> Dfs<A> {
+        Dfs {
+            graph: self,
+            stack: vec![start],
+            seen: BitSet::default(),
+        }
+    }
+}
+
+pub struct Bfs<'a, A> {
+    graph: &'a Graph<A>,
+    queue: VecDeque<usize>,
+    seen: BitSet,
+}
+
+impl<A> Iterator for Bfs<'_, A> {
+    type Item = usize;
+
+    fn next(&mut self) -> Option<usize> {
+        let id = self.queue.pop_front()?;
+        for next in self.graph.neighbours(id) {
+            if !self.seen.is_set(*next) {
+                self.seen.set_mut(*next);
+                self.queue.push_back(*next);
+            }
+        }
+        Some(id)
+    }
+}
+
+pub struct Dfs<'a, A> {
+    graph: &'a Graph<A>,
+    stack: Vec<usize>,
+    seen: BitSet,
+}
+
+impl<A> Iterator for Dfs<'_, A> {
+    type Item = usize;
+
+    fn next(&mut self) -> Option<usize> {
+        loop {
+            let id = self.stack.pop()?;
+            if !self.seen.is_set(id) {
+                self.seen.set_mut(id);
+                // reversed, so that the first neighbour is visited first
+                self.stack.extend(self.graph.neighbours(id).iter().rev().filter(|next| !self.seen.is_set(**next)));
+                return Some(id);
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_graph() {
+        let mut graph = Graph::<bool>::new();
+        graph.add_undirected_edge("a", "b");
+        graph.add_undirected_edge("a", "c");
+        graph.add_undirected_edge("b", "d");
+        graph.add_edge("e", "a");
+        *graph.attribute_mut(graph.id("c").unwrap()) = true;
+
+        assert_eq!((graph.len(), graph.id("d"), graph.name(4)), (5, Some(3), "e"));
+        assert!(*graph.attribute(2) && !*graph.attribute(0));
+
+        let names = |ids: Vec<usize>| ids.into_iter().map(|id| graph.name(id)).collect::<String>();
+        assert_eq!(names(graph.bfs(0).collect()), "abcd");
+        assert_eq!(names(graph.dfs(0).collect()), "abdc");
+        assert_eq!(names(graph.dfs(4).collect()), "eabdc");
+    }
+}
@@ -0,0 +1,242 @@
+use crate::parse::Block;
+use crate::prelude::*;
+use std::fmt::{Display, Formatter};
+use std::ops::{Index, IndexMut};
+
+/// Offsets of the orthogonal neighbours, in reading order
+pub const NEIGHBOURS4: [(isize, isize); 4] = [(0, -1), (-1, 0), (1, 0), (0, 1)];
+
+/// Offsets of the orthogonal and diagonal neighbours, in reading order
+pub const NEIGHBOURS8: [(isize, isize); 8] = [(-1, -1), (0, -1), (1, -1), (-1, 0), (1, 0), (-1, 1), (0, 1), (1, 1)];
+
+/// A rectangular grid stored row by row, indexed by `(x, y)` positions
+///
+/// A wrapping grid is a torus: positions leaving one edge come back in on the opposite one.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Grid<T> {
+    width: usize,
+    height: usize,
+    cells: Vec<T>,
+    wrapping: bool,
+}
+
+impl<T> Grid<T> {
+    pub fn new(width: usize, height: usize, value: T) -> Self
+    where
+        T: Clone,
+    {
+        Grid {
+            width,
+            height,
+            cells: vec![value; width * height],
+            wrapping: false,
+        }
+    }
+
+    pub fn from_fn(width: usize, height: usize, mut f: impl FnMut(usize, usize) -> T) -> Self {
+        let cells = (0..height).flat_map(|y| (0..width).map(move |x| (x, y))).map(|(x, y)| f(x, y)).collect();
+        Grid {
+            width,
+            height,
+            cells,
+            wrapping: false,
+        }
+    }
+
+    /// Builds a grid from cells in row order
+    pub fn from_vec(width: usize, cells: Vec<T>) -> Result<Self> {
+        if width == 0 || cells.len() % width != 0 {
+            return Err(Error::General(format!("{} cells do not fit a width of {}", cells.len(), width)));
+        }
+        Ok(Grid {
+            width,
+            height: cells.len() / width,
+            cells,
+            wrapping: false,
+        })
+    }
+
+    /// Parses one row per line, mapping every character with `f`
+    pub fn parse_with(input: &str, f: impl FnMut(char) -> Result<T>) -> Result<Self> {
+        Block::new(input.trim_end()).grid(f)
+    }
+
+    /// Turns the grid into a torus
+    pub fn wrapping(mut self) -> Self {
+        self.wrapping = true;
+        self
+    }
+
+    pub fn is_wrapping(&self) -> bool {
+        self.wrapping
+    }
+
+    pub fn width(&self) -> usize {
+        self.width
+    }
+
+    pub fn height(&self) -> usize {
+        self.height
+    }
+
+    pub fn len(&self) -> usize {
+        self.cells.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.cells.is_empty()
+    }
+
+    /// All cells in row order
+    pub fn cells(&self) -> &[T] {
+        &self.cells
+    }
+
+    pub fn cells_mut(&mut self) -> &mut [T] {
+        &mut self.cells
+    }
+
+    /// Maps signed coordinates to a position inside the grid, wrapping them around on a torus
+    pub fn position(&self, x: isize, y: isize) -> Option<(usize, usize)> {
+        if self.wrapping && !self.is_empty() {
+            Some((x.rem_euclid(self.width as isize) as usize, y.rem_euclid(self.height as isize) as usize))
+        } else if x >= 0 && (x as usize) < self.width && y >= 0 && (y as usize) < self.height {
+            Some((x as usize, y as usize))
+        } else {
+            None
+        }
+    }
+
+    pub fn offset(&self, (x, y): (usize, usize), (dx, dy): (isize, isize)) -> Option<(usize, usize)> {
+        self.position(x as isize + dx, y as isize + dy)
+    }
+
+    pub fn get(&self, x: isize, y: isize) -> Option<&T> {
+        let (x, y) = self.position(x, y)?;
+        Some(&self.cells[y * self.width + x])
+    }
+
+    pub fn get_mut(&mut self, x: isize, y: isize) -> Option<&mut T> {
+        let (x, y) = self.position(x, y)?;
+        Some(&mut self.cells[y * self.width + x])
+    }
+
+    /// All positions in row order
+    pub fn positions(&self) -> impl Iterator<Item = (usize, usize)> {
+        let width = self.width;
+        (0..self.height).flat_map(move |y| (0..width).map(move |x| (x, y)))
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item = ((usize, usize), &T)> {
+        self.positions().zip(self.cells.iter())
+    }
+
+    pub fn neighbours4(&self, position: (usize, usize)) -> impl Iterator<Item = (usize, usize)> + '_ {
+        NEIGHBOURS4.iter().filter_map(move |delta| self.offset(position, *delta))
+    }
+
+    pub fn neighbours8(&self, position: (usize, usize)) -> impl Iterator<Item = (usize, usize)> + '_ {
+        NEIGHBOURS8.iter().filter_map(move |delta| self.offset(position, *delta))
+    }
+
+    pub fn row(&self, y: usize) -> &[T] {
+        &self.cells[y * self.width..(y + 1) * self.width]
+    }
+
+    pub fn row_mut(&mut self, y: usize) -> &mut [T] {
+        &mut self.cells[y * self.width..(y + 1) * self.width]
+    }
+
+    pub fn rows(&self) -> impl Iterator<Item = &[T]> {
+        self.cells.chunks(self.width.max(1))
+    }
+
+    pub fn column(&self, x: usize) -> impl Iterator<Item = &T> {
+        assert!(x < self.width, "column {} out of bounds", x);
+        self.cells.iter().skip(x).step_by(self.width)
+    }
+
+    pub fn columns(&self) -> impl Iterator<Item = impl Iterator<Item = &T>> {
+        (0..self.width).map(move |x| self.column(x))
+    }
+
+    pub fn map<U>(&self, f: impl FnMut(&T) -> U) -> Grid<U> {
+        Grid {
+            width: self.width,
+            height: self.height,
+            cells: self.cells.iter().map(f).collect(),
+            wrapping: self.wrapping,
+        }
+    }
+}
+
+impl Grid<u8> {
+    /// Parses a grid of single decimal digits
+    pub fn parse_digits(input: &str) -> Result<Self> {
+        Block::new(input.trim_end()).digit_grid()
+    }
+}
+
+impl Grid<char> {
+    pub fn parse_chars(input: &str) -> Result<Self> {
+        Self::parse_with(input, Ok)
+    }
+}
+
+impl<T> Index<(usize, usize)> for Grid<T> {
+    type Output = T;
+
+    fn index(&self, (x, y): (usize, usize)) -> &T {
+        debug_assert!(x < self.width);
+        &self.cells[y * self.width + x]
+    }
+}
+
+impl<T> IndexMut<(usize, usize)> for Grid<T> {
+    fn index_mut(&mut self, (x, y): (usize, usize)) -> &mut T {
+        debug_assert!(x < self.width);
+        &mut self.cells[y * self.width + x]
+    }
+}
+
+/// Writes the cells of each row next to each other, one row per line
+impl<T: Display> Display for Grid<T> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        for (y, row) in self.rows().enumerate() {
+            if y > 0 {
+                f.write_str("\n")?;
+            }
+            for cell in row {
+                Display::fmt(cell, f)?;
+            }
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_display() {
+        let grid = Grid::parse_digits("123\n456\n").unwrap();
+        assert_eq!((grid.width(), grid.height()), (3, 2));
+        assert_eq!(grid[(2, 1)], 6);
+        assert_eq!(grid.column(1).copied().collect::<Vec<_>>(), vec![2, 5]);
+        assert_eq!(grid.to_string(), "123\n456");
+        assert!(Grid::parse_digits("12\n345").is_err());
+    }
+
+    #[test]
+    fn test_neighbours() {
+        let grid = Grid::new(3, 3, 0);
+        assert_eq!(grid.neighbours4((0, 0)).collect::<Vec<_>>(), vec![(1, 0), (0, 1)]);
+        assert_eq!(grid.neighbours8((1, 1)).count(), 8);
+
+        let grid = grid.wrapping();
+        assert_eq!(grid.neighbours4((0, 0)).collect::<Vec<_>>(), vec![(0, 2), (2, 0), (1, 0), (0, 1)]);
+        assert_eq!(grid.get(-1, 3), Some(&0));
+        assert_eq!(grid.position(-1, 3), Some((2, 0)));
+    }
+}
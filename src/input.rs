@@ -0,0 +1,95 @@
+use crate::prelude::*;
+use std::fmt::{Display, Formatter};
+use std::io::{BufRead, BufReader};
+use std::path::PathBuf;
+
+/// Environment variable overriding the directory containing the `aN_input.txt` and `aN_example*.txt` files
+pub const DATA_DIR_ENV: &str = "AOC_DATA_DIR";
+
+/// Where to read the puzzle input of a day from
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Input {
+    /// An explicit file path
+    Path(PathBuf),
+    /// Standard input
+    Stdin,
+    /// The puzzle input of a day, `data/aN_input.txt`
+    Puzzle(usize),
+    /// An example of a day, `data/aN_example{suffix}.txt`, for example `""`, `"2"` or `"_2d"`
+    Example(usize, String),
+}
+
+/// The `data` directory relative to the working directory, unless overridden by [`DATA_DIR_ENV`]
+pub fn data_dir() -> PathBuf {
+    std::env::var_os(DATA_DIR_ENV).map_or_else(|| PathBuf::from("data"), PathBuf::from)
+}
+
+/// Lists the example suffixes available for a day, sorted by name
+pub fn examples(day: usize) -> Result<Vec<String>> {
+    let prefix = format!("a{}_example", day);
+    let mut suffixes = std::fs::read_dir(data_dir())?
+        .filter_map(|entry| {
+            let name = entry.ok()?.file_name().into_string().ok()?;
+            Some(name.strip_prefix(&prefix)?.strip_suffix(".txt")?.to_string())
+        })
+        .collect::<Vec<_>>();
+    suffixes.sort();
+    Ok(suffixes)
+}
+
+impl Input {
+    /// Parses the command line of a single day binary: `[PATH | - | --example [SUFFIX]]`
+    pub fn from_args(day: usize, mut args: impl Iterator<Item = String>) -> Result<Input> {
+        let input = match args.next().as_deref() {
+            None => Input::Puzzle(day),
+            Some("-") => Input::Stdin,
+            Some("--example") => Input::Example(day, args.next().unwrap_or_default()),
+            Some(path) => Input::Path(PathBuf::from(path)),
+        };
+        if let Some(arg) = args.next() {
+            return Err(Error::General(format!("Unexpected argument {}", arg)));
+        }
+        Ok(input)
+    }
+
+    /// The file backing this input, `None` for stdin
+    pub fn path(&self) -> Option<PathBuf> {
+        match self {
+            Input::Path(path) => Some(path.clone()),
+            Input::Stdin => None,
+            Input::Puzzle(day) => Some(data_dir().join(format!("a{}_input.txt", day))),
+            Input::Example(day, suffix) => Some(data_dir().join(format!("a{}_example{}.txt", day, suffix))),
+        }
+    }
+
+    pub fn lines(&self) -> Result<Vec<String>> {
+        match self.path() {
+            Some(path) => read_lines(&path).context(path.display()),
+            None => Ok(std::io::stdin().lock().lines().collect::<std::io::Result<Vec<String>>>()?),
+        }
+    }
+
+    /// Opens the input for reading it line by line, without loading it into memory
+    pub fn reader(&self) -> Result<Box<dyn BufRead>> {
+        match self.path() {
+            Some(path) => Ok(Box::new(BufReader::new(std::fs::File::open(&path).context(path.display())?))),
+            None => Ok(Box::new(BufReader::new(std::io::stdin()))),
+        }
+    }
+
+    /// Reads the whole input, normalizing line endings to `\n`
+    pub fn read(&self) -> Result<String> {
+        let mut data = self.lines()?.join("\n");
+        data.push('\n');
+        Ok(data)
+    }
+}
+
+impl Display for Input {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self.path() {
+            Some(path) => f.write_fmt(format_args!("{}", path.display())),
+            None => f.write_str("<stdin>"),
+        }
+    }
+}
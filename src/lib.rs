@@ -1,9 +1,16 @@
+#![feature(array_windows)]
+#![feature(array_chunks)]
+#![feature(box_patterns)]
+#![feature(core_intrinsics)]
+#![feature(int_abs_diff)]
+
 use std::array::TryFromSliceError;
 use std::fmt::{Debug, Display, Formatter};
 use std::fs::File;
 use std::io::{BufRead, BufReader};
+use std::ops::{Bound, RangeBounds};
 use std::path::Path;
-use std::str::Utf8Error;
+use std::str::{FromStr, Utf8Error};
 
 // See https://en.wikipedia.org/wiki/Block_Elements
 pub const BLOCK_SOLID: char = '\u{2588}';
@@ -20,6 +27,38 @@ pub enum Error {
     General(String),
     PatternMatch,
     EmptyIterator,
+    /// An error located in the puzzle input
+    Parse(Box<ParseError>),
+    /// An error with a message describing what was being done
+    Context(String, Box<Error>),
+}
+
+/// Where in the puzzle input an error occurred, every part of the location is optional
+#[derive(Debug)]
+pub struct ParseError {
+    pub file: Option<String>,
+    /// 1-based line number
+    pub line: Option<usize>,
+    /// 1-based column of the offending text within the line
+    pub column: Option<usize>,
+    /// The offending line
+    pub text: Option<String>,
+    pub cause: Error,
+}
+
+impl Error {
+    fn into_parse(self) -> Box<ParseError> {
+        match self {
+            Error::Parse(e) => e,
+            cause => Box::new(ParseError {
+                file: None,
+                line: None,
+                column: None,
+                text: None,
+                cause,
+            }),
+        }
+    }
 }
 
 pub type Result<T> = std::result::Result<T, Error>;
@@ -70,16 +109,181 @@ impl Display for Error {
             Error::General(s) => f.write_fmt(format_args!("General: {}", s)),
             Error::PatternMatch => f.write_str("Pattern mismatch"),
             Error::EmptyIterator => f.write_str("Empty iterator"),
+            Error::Parse(e) => {
+                // file:line:column like compilers do, spelled out when there is no file
+                let location = match &e.file {
+                    Some(file) => [Some(file.clone()), e.line.map(|l| l.to_string()), e.column.map(|c| c.to_string())]
+                        .into_iter()
+                        .flatten()
+                        .collect::<Vec<_>>()
+                        .join(":"),
+                    None => [e.line.map(|l| format!("line {}", l)), e.column.map(|c| format!("column {}", c))]
+                        .into_iter()
+                        .flatten()
+                        .collect::<Vec<_>>()
+                        .join(", "),
+                };
+                if !location.is_empty() {
+                    f.write_fmt(format_args!("{}: ", location))?;
+                }
+                Display::fmt(&e.cause, f)?;
+                if let Some(text) = &e.text {
+                    f.write_fmt(format_args!(" in {:?}", text))?;
+                }
+                Ok(())
+            }
+            Error::Context(context, e) => f.write_fmt(format_args!("{}: {}", context, e)),
         }
     }
 }
 
 impl std::error::Error for Error {}
 
+/// Adds context to the error of a result, used by the parsers to locate errors in the input
+pub trait Context<T> {
+    fn context(self, message: impl Display) -> Result<T>;
+    /// Locates the error on a line of the input, unless it is already located on an inner line
+    fn at_line(self, line: usize, text: &str) -> Result<T>;
+    fn at_column(self, column: usize) -> Result<T>;
+    fn in_file(self, file: impl Display) -> Result<T>;
+}
+
+impl<T, E: Into<Error>> Context<T> for std::result::Result<T, E> {
+    fn context(self, message: impl Display) -> Result<T> {
+        self.map_err(|e| Error::Context(message.to_string(), Box::new(e.into())))
+    }
+
+    fn at_line(self, line: usize, text: &str) -> Result<T> {
+        self.map_err(|e| {
+            let mut e = e.into().into_parse();
+            if e.line.is_none() {
+                e.line = Some(line);
+                e.text = Some(text.to_string());
+            }
+            Error::Parse(e)
+        })
+    }
+
+    fn at_column(self, column: usize) -> Result<T> {
+        self.map_err(|e| {
+            let mut e = e.into().into_parse();
+            e.column.get_or_insert(column);
+            Error::Parse(e)
+        })
+    }
+
+    fn in_file(self, file: impl Display) -> Result<T> {
+        self.map_err(|e| {
+            let mut e = e.into().into_parse();
+            e.file.get_or_insert_with(|| file.to_string());
+            Error::Parse(e)
+        })
+    }
+}
+
+/// Implements the bitwise operators and their assign variants through a `zip_with(&self, &other, f)` method
+///
+/// `a - b` is the set difference `a.and_not(&b)`.
+macro_rules! bit_ops {
+    ($ty:ty $(, $($generics:tt)+)?) => {
+        impl<$($($generics)+)?> std::ops::BitAnd for $ty {
+            type Output = Self;
+
+            #[inline]
+            fn bitand(self, other: Self) -> Self {
+                self.zip_with(&other, |a, b| a & b)
+            }
+        }
+
+        impl<$($($generics)+)?> std::ops::BitOr for $ty {
+            type Output = Self;
+
+            #[inline]
+            fn bitor(self, other: Self) -> Self {
+                self.zip_with(&other, |a, b| a | b)
+            }
+        }
+
+        impl<$($($generics)+)?> std::ops::BitXor for $ty {
+            type Output = Self;
+
+            #[inline]
+            fn bitxor(self, other: Self) -> Self {
+                self.zip_with(&other, |a, b| a ^ b)
+            }
+        }
+
+        impl<$($($generics)+)?> std::ops::Sub for $ty {
+            type Output = Self;
+
+            #[inline]
+            fn sub(self, other: Self) -> Self {
+                self.zip_with(&other, |a, b| a & !b)
+            }
+        }
+
+        impl<$($($generics)+)?> std::ops::Not for $ty {
+            type Output = Self;
+
+            #[inline]
+            fn not(self) -> Self {
+                self.zip_with(&self, |a, _| !a)
+            }
+        }
+
+        impl<$($($generics)+)?> std::ops::BitAndAssign for $ty {
+            #[inline]
+            fn bitand_assign(&mut self, other: Self) {
+                *self = *self & other;
+            }
+        }
+
+        impl<$($($generics)+)?> std::ops::BitOrAssign for $ty {
+            #[inline]
+            fn bitor_assign(&mut self, other: Self) {
+                *self = *self | other;
+            }
+        }
+
+        impl<$($($generics)+)?> std::ops::BitXorAssign for $ty {
+            #[inline]
+            fn bitxor_assign(&mut self, other: Self) {
+                *self = *self ^ other;
+            }
+        }
+
+        impl<$($($generics)+)?> std::ops::SubAssign for $ty {
+            #[inline]
+            fn sub_assign(&mut self, other: Self) {
+                *self = *self - other;
+            }
+        }
+    };
+}
+
 #[derive(Clone,Copy,Default,Eq, PartialEq)]
 pub struct Bitmap64(u64);
 
+bit_ops!(Bitmap64);
+
 impl Bitmap64 {
+    /// The values in `range`, which must lie within `0..=64`
+    pub fn range(range: impl RangeBounds<usize>) -> Self {
+        let start = match range.start_bound() {
+            Bound::Included(start) => *start,
+            Bound::Excluded(start) => start + 1,
+            Bound::Unbounded => 0,
+        };
+        let end = match range.end_bound() {
+            Bound::Included(end) => end + 1,
+            Bound::Excluded(end) => *end,
+            Bound::Unbounded => 64,
+        };
+        assert!(end <= 64, "range end {} out of bounds", end);
+        let below = |n: usize| if n >= 64 { u64::MAX } else { (1 << n) - 1 };
+        Bitmap64(below(end) & !below(start.min(end)))
+    }
+
     #[inline]
     pub fn is_empty(&self) -> bool {
         self.0 == 0
@@ -139,10 +343,75 @@ impl Bitmap64 {
         Bitmap64(self.0 | other.0)
     }
 
+    #[inline]
+    pub fn is_subset(&self, other: &Bitmap64) -> bool {
+        self.0 & !other.0 == 0
+    }
+
+    /// The smallest value in the set
+    #[inline]
+    pub fn first(&self) -> Option<usize> {
+        (self.0 != 0).then(|| self.0.trailing_zeros() as usize)
+    }
+
+    /// The largest value in the set
+    #[inline]
+    pub fn last(&self) -> Option<usize> {
+        (self.0 != 0).then(|| 63 - self.0.leading_zeros() as usize)
+    }
+
+    /// Number of values in the set smaller than `value`
+    #[inline]
+    pub fn rank(&self, value: usize) -> usize {
+        (self.0 & Bitmap64::range(..value.min(64)).0).count_ones() as usize
+    }
+
+    /// The `n`th smallest value in the set, counting from 0
+    pub fn select(&self, n: usize) -> Option<usize> {
+        self.iter().nth(n)
+    }
+
     #[inline]
     pub fn iter(&self) -> Bitmap64Iter {
         Bitmap64Iter(self.0)
     }
+
+    /// All subsets in ascending order of their masks, from the empty set to the set itself
+    pub fn subsets(&self) -> Subsets {
+        Subsets {
+            mask: self.0,
+            next: Some(0),
+        }
+    }
+
+    /// All subsets with `k` values, in ascending order of their masks
+    pub fn subsets_of_size(&self, k: usize) -> SubsetsOfSize {
+        let len = self.len();
+        SubsetsOfSize {
+            mask: *self,
+            next: (k <= len).then(|| (1_u128 << k) - 1),
+            limit: 1_u128 << len,
+        }
+    }
+
+    #[inline]
+    fn zip_with(&self, other: &Bitmap64, f: impl Fn(u64, u64) -> u64) -> Self {
+        Bitmap64(f(self.0, other.0))
+    }
+}
+
+/// Lists the values like a set, `{1, 4, 5}`
+impl Display for Bitmap64 {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        f.write_str("{")?;
+        for (i, value) in self.iter().enumerate() {
+            if i > 0 {
+                f.write_str(", ")?;
+            }
+            Display::fmt(&value, f)?;
+        }
+        f.write_str("}")
+    }
 }
 
 impl Debug for Bitmap64 {
@@ -196,63 +465,69 @@ impl Iterator for Bitmap64Iter {
     }
 }
 
-pub fn read_lines(file: &str) -> Result<Vec<String>> {
-    let path = Path::new(file);
-    let io = File::open(path)?;
-    let br = BufReader::new(io);
-    Ok(br.lines().collect::<std::io::Result<Vec<String>>>()?)
+/// Iterates the subsets of a [`Bitmap64`]
+pub struct Subsets {
+    mask: u64,
+    next: Option<u64>,
 }
 
-pub fn get_nested_relative<T>(data: &[impl AsRef<[T]>], row: usize, col: usize, delta_row: isize, delta_col: isize) -> Option<&T> {
-    let y = (row as isize) + delta_col;
-    let x = (col as isize) + delta_row;
-    get_nested(data, y, x)
-}
+impl Iterator for Subsets {
+    type Item = Bitmap64;
 
-pub fn get_nested<T>(data: &[impl AsRef<[T]>], row: isize, col: isize) -> Option<&T> {
-    let (y, x) = (row, col);
-    if y >= 0 && (y as usize) < data.len() {
-        let row = data[y as usize].as_ref();
-        if x >= 0 && (x as usize) < row.len() {
-            Some(&row[x as usize])
-        } else {
-            None
-        }
-    } else {
-        None
+    #[inline]
+    fn next(&mut self) -> Option<Self::Item> {
+        let subset = self.next?;
+        // the next larger subset, wrapping around to the empty set after the mask itself
+        let next = subset.wrapping_sub(self.mask) & self.mask;
+        self.next = (next != 0).then(|| next);
+        Some(Bitmap64(subset))
     }
 }
 
-pub fn get_2d_relative<T, const W: usize>(map: &[T], y: usize, x: usize, dy: isize, dx: isize) -> Option<&T> {
-    let y = (y as isize) + dy;
-    let x = (x as isize) + dx;
-    get_2d::<T, W>(map, y, x)
+/// Iterates the subsets of a [`Bitmap64`] with a fixed number of values
+pub struct SubsetsOfSize {
+    mask: Bitmap64,
+    /// Which of the values of the mask are in the next subset, as a combination of `len` bits
+    next: Option<u128>,
+    limit: u128,
 }
 
-pub fn get_2d<T, const W: usize>(map: &[T], y: isize, x: isize) -> Option<&T> {
-    debug_assert!(map.len() % W == 0);
-    let height = map.len() / W;
-    if y >= 0 && (y as usize) < height && x >= 0 && (x as usize) < W {
-        Some(&map[(y as usize) * W + (x as usize)])
-    } else {
-        None
+impl Iterator for SubsetsOfSize {
+    type Item = Bitmap64;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let combination = self.next?;
+
+        // Gosper's hack: the next larger number with the same number of ones
+        self.next = if combination == 0 {
+            None
+        } else {
+            let lowest = combination & combination.wrapping_neg();
+            let ripple = combination + lowest;
+            let next = ripple | (((ripple ^ combination) / lowest) >> 2);
+            (next < self.limit).then(|| next)
+        };
+
+        Some(self.mask.iter().enumerate().filter(|(i, _)| combination & (1 << i) != 0).map(|(_, value)| value).collect())
     }
 }
 
-pub fn get_2d_relative_mut<T, const W: usize>(map: &mut [T], y: usize, x: usize, dy: isize, dx: isize) -> Option<&mut T> {
-    let y = (y as isize) + dy;
-    let x = (x as isize) + dx;
-    get_2d_mut::<T, W>(map, y, x)
+pub fn read_lines(file: impl AsRef<Path>) -> Result<Vec<String>> {
+    let io = File::open(file)?;
+    let br = BufReader::new(io);
+    Ok(br.lines().collect::<std::io::Result<Vec<String>>>()?)
 }
 
-pub fn get_2d_mut<T, const W: usize>(map: &mut [T], y: isize, x: isize) -> Option<&mut T> {
-    debug_assert!(map.len() % W == 0);
-    let height = map.len() / W;
-    if y >= 0 && (y as usize) < height && x >= 0 && (x as usize) < W {
-        Some(&mut map[(y as usize) * W + (x as usize)])
-    } else {
-        None
-    }
+/// Parses a group of a regex match, locating errors at the column the group starts at
+pub fn parse_capture<T>(captures: &regex::Captures, index: usize) -> Result<T>
+where
+    T: FromStr,
+    T::Err: Into<Error>,
+{
+    let group = captures
+        .get(index)
+        .ok_or_else(|| Error::General(format!("Missing capture group {}", index)))?;
+    group.as_str().parse::<T>().at_column(group.start() + 1)
 }
 
 #[macro_export]
@@ -263,12 +538,275 @@ macro_rules! regex {
     }};
 }
 
+/// Writes a diagnostic message to stderr if its [`log::Level`] is enabled, formatting it only then
+#[macro_export]
+macro_rules! log {
+    ($level:expr, $($arg:tt)+) => {
+        if $crate::log::enabled($level) {
+            $crate::log::write($level, module_path!(), format_args!($($arg)+));
+        }
+    };
+}
+
+/// Logs progress and results worth a look when the answer is off
+#[macro_export]
+macro_rules! info {
+    ($($arg:tt)+) => { $crate::log!($crate::log::Level::Info, $($arg)+) };
+}
+
+/// Logs intermediate results of a solver
+#[macro_export]
+macro_rules! debug {
+    ($($arg:tt)+) => { $crate::log!($crate::log::Level::Debug, $($arg)+) };
+}
+
+/// Logs the details of individual steps, which can be a lot
+#[macro_export]
+macro_rules! trace {
+    ($($arg:tt)+) => { $crate::log!($crate::log::Level::Trace, $($arg)+) };
+}
+
+pub mod bitset;
+pub mod cycle;
+pub mod graph;
+pub mod grid;
+pub mod input;
+pub mod log;
+pub mod memo;
+pub mod parse;
+pub mod point;
+pub mod render;
+pub mod search;
+pub mod sparse;
+pub mod visualize;
+
+pub mod day1;
+pub mod day2;
+pub mod day3;
+pub mod day4;
+pub mod day5;
+pub mod day6;
+pub mod day7;
+pub mod day8;
+pub mod day9;
+pub mod day10;
+pub mod day11;
+pub mod day12;
+pub mod day13;
+pub mod day14;
+pub mod day15;
+pub mod day16;
+pub mod day17;
+pub mod day18;
+pub mod day19;
+pub mod day20;
+pub mod day21;
+pub mod day22;
+pub mod day23;
+pub mod day24;
+pub mod day25;
+
+/// A puzzle solution, split into parsing the input and solving both parts
+pub trait Solution {
+    const DAY: usize;
+    /// Number of puzzle parts, the last day only has a single one
+    const PARTS: usize = 2;
+
+    type Input;
+    type Part1: Display;
+    type Part2: Display;
+
+    fn parse(input: &str) -> Result<Self::Input>;
+    fn part1(input: &Self::Input) -> Result<Self::Part1>;
+    fn part2(input: &Self::Input) -> Result<Self::Part2>;
+
+    /// Other implementations of the parts, benchmarked side by side with `part1` and `part2`
+    fn alternatives() -> Vec<Alternative<Self::Input>> {
+        Vec::new()
+    }
+
+    /// Renders the grid a part ends up with, for days which have one
+    fn render(_input: &Self::Input, _part: usize) -> Result<Option<render::Image>> {
+        Ok(None)
+    }
+
+    /// Steps through the puzzle for the visualizer, for days which evolve a state
+    fn simulation(_input: &Self::Input) -> Option<BoxedSimulation> {
+        None
+    }
+
+    /// Parses the input and solves a single part, formatting the answer
+    fn solve(input: &str, part: usize) -> Result<String>
+    where
+        Self: Sized,
+    {
+        solve_part::<Self>(&Self::parse(input)?, part)
+    }
+}
+
+fn solve_part<S: Solution>(input: &S::Input, part: usize) -> Result<String> {
+    match part {
+        1 => Ok(S::part1(input)?.to_string()),
+        2 if S::PARTS >= 2 => Ok(S::part2(input)?.to_string()),
+        _ => Err(Error::General(format!("Day {} has no part {}", S::DAY, part))),
+    }
+}
+
+/// A named alternative implementation of a part of a [`Solution`]
+pub struct Alternative<I> {
+    pub name: &'static str,
+    pub solve: Box<dyn Fn(&I) -> Result<String>>,
+}
+
+impl<I> Alternative<I> {
+    pub fn new<T: Display>(name: &'static str, solve: impl Fn(&I) -> Result<T> + 'static) -> Self {
+        Alternative {
+            name,
+            solve: Box::new(move |input| Ok(solve(input)?.to_string())),
+        }
+    }
+}
+
+/// A [`visualize::Simulation`] created from a day's input
+pub type BoxedSimulation = Box<dyn visualize::Simulation>;
+
+/// Type-erased [`Solution`] of a single day
+#[derive(Clone, Copy)]
+pub struct Day {
+    pub day: usize,
+    pub parts: usize,
+    pub solve: fn(&str, usize) -> Result<String>,
+    /// Like `solve`, but locates the errors of parsing the input in `file`
+    pub solve_in: fn(&str, usize, &dyn Display) -> Result<String>,
+    pub render: fn(&str, usize) -> Result<Option<render::Image>>,
+    pub simulate: fn(&str) -> Result<Option<BoxedSimulation>>,
+}
+
+fn solve_in_day<S: Solution>(input: &str, part: usize, file: &dyn Display) -> Result<String> {
+    solve_part::<S>(&S::parse(input).in_file(file)?, part)
+}
+
+fn render_day<S: Solution>(input: &str, part: usize) -> Result<Option<render::Image>> {
+    S::render(&S::parse(input)?, part)
+}
+
+fn simulate_day<S: Solution>(input: &str) -> Result<Option<BoxedSimulation>> {
+    Ok(S::simulation(&S::parse(input)?))
+}
+
+macro_rules! day {
+    ($solution:ty) => {
+        Day {
+            day: <$solution as Solution>::DAY,
+            parts: <$solution as Solution>::PARTS,
+            solve: <$solution as Solution>::solve,
+            solve_in: solve_in_day::<$solution>,
+            render: render_day::<$solution>,
+            simulate: simulate_day::<$solution>,
+        }
+    };
+}
+
+pub static DAYS: [Day; 25] = [
+    day!(day1::Day1),
+    day!(day2::Day2),
+    day!(day3::Day3),
+    day!(day4::Day4),
+    day!(day5::Day5),
+    day!(day6::Day6),
+    day!(day7::Day7),
+    day!(day8::Day8),
+    day!(day9::Day9),
+    day!(day10::Day10),
+    day!(day11::Day11),
+    day!(day12::Day12),
+    day!(day13::Day13),
+    day!(day14::Day14),
+    day!(day15::Day15),
+    day!(day16::Day16),
+    day!(day17::Day17),
+    day!(day18::Day18),
+    day!(day19::Day19),
+    day!(day20::Day20),
+    day!(day21::Day21),
+    day!(day22::Day22),
+    day!(day23::Day23),
+    day!(day24::Day24),
+    day!(day25::Day25),
+];
+
+/// Entry point of the single day binaries, solves all parts for the input given on the command line
+///
+/// `--image DIR` additionally writes the images rendered for each part into `DIR`,
+/// `-v`, `-vv` or `-vvv` enable the diagnostic output of the [`log`](mod@log) levels info, debug or trace.
+pub fn run_day<S: Solution>() {
+    if let Err(e) = try_run_day::<S>() {
+        eprintln!("Error: {}", e);
+        std::process::exit(1);
+    }
+}
+
+fn try_run_day<S: Solution>() -> Result<()> {
+    let mut args = std::env::args().skip(1).collect::<Vec<_>>();
+    if let Some(level) = log::take_verbosity(&mut args) {
+        log::set_level(level);
+    }
+    let image_dir = match args.iter().position(|arg| arg == "--image") {
+        Some(i) if i + 1 < args.len() => Some(std::path::PathBuf::from(args.drain(i..i + 2).nth(1).unwrap())),
+        Some(_) => return Err(Error::General("Missing directory for --image".into())),
+        None => None,
+    };
+
+    let source = input::Input::from_args(S::DAY, args.into_iter())?;
+    let input = S::parse(&source.read()?).in_file(&source)?;
+
+    for part in 1..=S::PARTS {
+        match part {
+            1 => print_answer(1, S::part1(&input)?),
+            _ => print_answer(2, S::part2(&input)?),
+        }
+        if let Some(dir) = &image_dir {
+            if let Some(image) = S::render(&input, part)? {
+                save_image(dir, S::DAY, part, &image)?;
+            }
+        }
+    }
+
+    Ok(())
+}
+
+/// Writes the image of a part into `dir`, reporting the file name on stderr
+pub fn save_image(dir: &Path, day: usize, part: usize, image: &render::Image) -> Result<()> {
+    let path = dir.join(image.file_name(day, part));
+    image.save(&path)?;
+    eprintln!("Wrote {}", path.display());
+    Ok(())
+}
+
+fn print_answer(part: usize, answer: impl Display) {
+    let answer = answer.to_string();
+    if answer.contains('\n') {
+        println!("Part {}:\n{}", part, answer);
+    } else {
+        println!("Part {}: {}", part, answer);
+    }
+}
 
 pub mod prelude {
     pub use super::*;
     pub use super::Error;
     pub use super::Result;
+    pub use super::{parse_capture, Context};
     pub use super::regex;
+    pub use super::{debug, info, trace};
+    pub use super::bitset::{BitSet, Bitmap};
+    pub use super::grid::Grid;
+    pub use super::sparse::SparseGrid;
+    pub use super::input::Input;
+    pub use super::point::{Point2, Point3};
+    pub use super::render::{ColourMap, Image, Rgb};
+    pub use super::visualize::Simulation;
+    pub use super::{Alternative, Solution};
     pub use std::str::FromStr;
 
     pub use regex::Regex;
@@ -276,7 +814,7 @@ pub mod prelude {
 
 #[cfg(test)]
 mod tests {
-    use crate::Bitmap64;
+    use crate::{Bitmap64, Context, Day, Error, DAYS};
 
     #[test]
     fn test_bitmap_iter() {
@@ -286,4 +824,53 @@ mod tests {
         assert_eq!(vec, vec![0,1, 4, 5, 7]);
 
     }
+
+    #[test]
+    fn test_bitmap_ops() {
+        let a = Bitmap64::range(2..6);
+        let b = Bitmap64::range(4..=8);
+        assert_eq!((a & b, a | b, a ^ b, a - b), (Bitmap64::range(4..6), Bitmap64::range(2..9), Bitmap64(0b1_1100_1100), Bitmap64::range(2..4)));
+        assert_eq!((!a).len(), 60);
+        assert_eq!(Bitmap64::range(..), Bitmap64(u64::MAX));
+
+        let mut c = a;
+        c -= Bitmap64::from(0b100);
+        c |= Bitmap64::from(0b1);
+        assert_eq!(c.to_string(), "{0, 3, 4, 5}");
+        assert_eq!((c.first(), c.last(), c.rank(4), c.select(1), c.select(4)), (Some(0), Some(5), 2, Some(3), None));
+    }
+
+    #[test]
+    fn test_subsets() {
+        let set = Bitmap64::from(0b10110);
+        assert_eq!(set.subsets().map(|s| s.as_u64()).collect::<Vec<_>>(), vec![0, 0b10, 0b100, 0b110, 0b10000, 0b10010, 0b10100, 0b10110]);
+        assert_eq!(set.subsets_of_size(2).map(|s| s.to_string()).collect::<Vec<_>>(), vec!["{1, 2}", "{1, 4}", "{2, 4}"]);
+        assert_eq!(set.subsets_of_size(0).count(), 1);
+        assert_eq!(set.subsets_of_size(4).count(), 0);
+        assert_eq!(Bitmap64::range(..).subsets_of_size(63).count(), 64);
+    }
+
+    #[test]
+    fn test_located_error() {
+        let e = "1,x".split_once(',').unwrap().1.parse::<i32>().at_column(3).at_line(7, "1,x");
+        assert_eq!(e.in_file("a.txt").unwrap_err().to_string(), "a.txt:7:3: Parse: invalid digit found in string in \"1,x\"");
+
+        let e: Result<(), Error> = Err(Error::PatternMatch);
+        assert_eq!(e.at_line(2, "?").context("Day 1").unwrap_err().to_string(), "Day 1: line 2: Pattern mismatch in \"?\"");
+    }
+
+    #[test]
+    fn test_registry() {
+        for (i, day) in DAYS.iter().enumerate() {
+            assert_eq!(day.day, i + 1);
+            assert_eq!(day.parts, if day.day == 25 { 1 } else { 2 });
+        }
+        assert_eq!((DAYS[0].solve)("199\n200\n208\n210\n", 1).unwrap(), "3");
+        assert!((DAYS[24].solve)("..\n..\n", 2).is_err());
+
+        // only parse errors are located in the file
+        let error = |day: &Day, input, part| (day.solve_in)(input, part, &"a.txt").unwrap_err().to_string();
+        assert_eq!(error(&DAYS[0], "x\n", 1), "a.txt:1: Parse: invalid digit found in string in \"x\"");
+        assert_eq!(error(&DAYS[24], "..\n..\n", 2), "General: Day 25 has no part 2");
+    }
 }
\ No newline at end of file
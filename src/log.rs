@@ -0,0 +1,121 @@
+use crate::prelude::*;
+use std::fmt::{Arguments, Display, Formatter};
+use std::sync::atomic::{AtomicU8, Ordering};
+
+/// Environment variable selecting the level, like `AOC_LOG=debug`
+pub const ENV_VAR: &str = "AOC_LOG";
+
+/// How much diagnostic output is written to stderr, every level includes the ones before
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum Level {
+    Off,
+    Info,
+    Debug,
+    Trace,
+}
+
+const LEVELS: [Level; 4] = [Level::Off, Level::Info, Level::Debug, Level::Trace];
+
+impl Level {
+    /// The level selected by repeating a verbosity flag `count` times, like `-vv`
+    pub fn from_verbosity(count: usize) -> Level {
+        LEVELS[count.min(LEVELS.len() - 1)]
+    }
+}
+
+impl FromStr for Level {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self> {
+        match s.to_ascii_lowercase().as_str() {
+            "off" => Ok(Level::Off),
+            "info" => Ok(Level::Info),
+            "debug" => Ok(Level::Debug),
+            "trace" => Ok(Level::Trace),
+            _ => Err(Error::General(format!("Invalid log level {}", s))),
+        }
+    }
+}
+
+impl Display for Level {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        f.write_str(match self {
+            Level::Off => "off",
+            Level::Info => "info",
+            Level::Debug => "debug",
+            Level::Trace => "trace",
+        })
+    }
+}
+
+const UNSET: u8 = u8::MAX;
+static LEVEL: AtomicU8 = AtomicU8::new(UNSET);
+
+/// The current level, read from [`ENV_VAR`] unless [`set_level`] was called first
+pub fn level() -> Level {
+    match LEVEL.load(Ordering::Relaxed) {
+        UNSET => {
+            let level = match std::env::var(ENV_VAR) {
+                Ok(value) => Level::from_str(&value).unwrap_or_else(|e| {
+                    eprintln!("Ignoring {}: {}", ENV_VAR, e);
+                    Level::Off
+                }),
+                Err(_) => Level::Off,
+            };
+            LEVEL.store(level as u8, Ordering::Relaxed);
+            level
+        }
+        level => LEVELS[level as usize],
+    }
+}
+
+pub fn set_level(level: Level) {
+    LEVEL.store(level as u8, Ordering::Relaxed);
+}
+
+/// Whether messages of `level` are written, messages are never logged at [`Level::Off`]
+pub fn enabled(level: Level) -> bool {
+    level != Level::Off && level <= self::level()
+}
+
+/// Removes the verbosity flags `-v`, `-vv`, ... and `--verbose` from the arguments, returning the level they select
+pub fn take_verbosity(args: &mut Vec<String>) -> Option<Level> {
+    let mut count = 0;
+    let mut found = false;
+    args.retain(|arg| {
+        let verbosity = match arg.as_str() {
+            "--verbose" => 1,
+            flag if flag.len() > 1 && flag.starts_with('-') && flag[1..].bytes().all(|b| b == b'v') => flag.len() - 1,
+            _ => return true,
+        };
+        count += verbosity;
+        found = true;
+        false
+    });
+
+    found.then(|| Level::from_verbosity(count))
+}
+
+/// Writes a message tagged with its level and the last segment of the module path, used by the logging macros
+#[doc(hidden)]
+pub fn write(level: Level, module: &str, args: Arguments) {
+    let module = module.rsplit("::").next().unwrap_or(module);
+    eprintln!("[{} {}] {}", level, module, args);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_verbosity() {
+        let mut args = ["run", "-vv", "--day", "3", "-v"].iter().map(|s| s.to_string()).collect::<Vec<_>>();
+        assert_eq!(take_verbosity(&mut args), Some(Level::Trace));
+        assert_eq!(args, ["run", "--day", "3"]);
+        assert_eq!(take_verbosity(&mut args), None);
+
+        assert_eq!(Level::from_verbosity(1), Level::Info);
+        assert_eq!("DEBUG".parse::<Level>().unwrap(), Level::Debug);
+        assert!("loud".parse::<Level>().is_err());
+    }
+}
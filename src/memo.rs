@@ -0,0 +1,125 @@
+use std::collections::HashMap;
+use std::fmt::{Display, Formatter};
+use std::hash::Hash;
+
+/// A cache for the results of a recursive function, keyed by its arguments
+///
+/// The function is written to take the cache as its first argument and wraps its body in [`Memo::get_or_insert_with`]:
+///
+/// ```
+/// use adventofcode2021::memo::Memo;
+///
+/// fn fibonacci(memo: &mut Memo<u64, u64>, n: u64) -> u64 {
+///     memo.get_or_insert_with(n, |memo| if n < 2 { n } else { fibonacci(memo, n - 1) + fibonacci(memo, n - 2) })
+/// }
+///
+/// let mut memo = Memo::new();
+/// assert_eq!(fibonacci(&mut memo, 90), 2880067194370816120);
+/// assert_eq!((memo.misses(), memo.hits()), (91, 88));
+/// ```
+#[derive(Debug, Clone)]
+pub struct Memo<K, V> {
+    cache: HashMap<K, V>,
+    hits: usize,
+    misses: usize,
+}
+
+impl<K, V> Default for Memo<K, V> {
+    fn default() -> Self {
+        Memo {
+            cache: HashMap::new(),
+            hits: 0,
+            misses: 0,
+        }
+    }
+}
+
+impl<K: Eq + Hash, V: Clone> Memo<K, V> {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// The cached value for `key`, computing it with `f` on a miss
+    ///
+    /// `f` gets the cache passed back in, so that it can recurse.
+    pub fn get_or_insert_with(&mut self, key: K, f: impl FnOnce(&mut Self) -> V) -> V {
+        if let Some(value) = self.cache.get(&key) {
+            self.hits += 1;
+            return value.clone();
+        }
+
+        self.misses += 1;
+        let value = f(self);
+        self.cache.insert(key, value.clone());
+        value
+    }
+
+    /// Number of lookups answered from the cache
+    pub fn hits(&self) -> usize {
+        self.hits
+    }
+
+    /// Number of lookups which had to be computed
+    pub fn misses(&self) -> usize {
+        self.misses
+    }
+
+    /// Share of the lookups answered from the cache, 0 before the first lookup
+    pub fn hit_rate(&self) -> f64 {
+        if self.hits + self.misses == 0 {
+            0.0
+        } else {
+            self.hits as f64 / (self.hits + self.misses) as f64
+        }
+    }
+
+    /// Number of cached values
+    pub fn len(&self) -> usize {
+        self.cache.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.cache.is_empty()
+    }
+}
+
+/// The hit and miss counts, for logging how well the cache works
+impl<K: Eq + Hash, V: Clone> Display for Memo<K, V> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "{} hits, {} misses, {:.1}% hit rate, {} entries",
+            self.hits,
+            self.misses,
+            self.hit_rate() * 100.0,
+            self.len()
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn paths(memo: &mut Memo<(u32, u32), u64>, x: u32, y: u32) -> u64 {
+        memo.get_or_insert_with((x, y), |memo| match (x, y) {
+            (0, _) | (_, 0) => 1,
+            _ => paths(memo, x - 1, y) + paths(memo, x, y - 1),
+        })
+    }
+
+    #[test]
+    fn test_memo() {
+        let mut memo = Memo::new();
+        assert_eq!((memo.hit_rate(), memo.is_empty()), (0.0, true));
+        assert_eq!(memo.to_string(), "0 hits, 0 misses, 0.0% hit rate, 0 entries");
+
+        // every point but the origin is computed once, only (1, 1) is reached from two sides
+        assert_eq!(paths(&mut memo, 2, 2), 6);
+        assert_eq!((memo.misses(), memo.hits(), memo.len()), (8, 1, 8));
+        assert_eq!(paths(&mut memo, 2, 2), 6);
+        assert_eq!((memo.misses(), memo.hits(), memo.len()), (8, 2, 8));
+        assert_eq!(memo.hit_rate(), 0.2);
+        assert_eq!(memo.to_string(), "2 hits, 8 misses, 20.0% hit rate, 8 entries");
+    }
+}
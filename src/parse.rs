@@ -0,0 +1,204 @@
+use crate::prelude::*;
+use regex::Captures;
+
+/// A part of the puzzle input which knows where it starts, so that errors are located in the whole input
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Block<'a> {
+    text: &'a str,
+    /// Number of lines before the block
+    offset: usize,
+}
+
+impl<'a> Block<'a> {
+    /// The whole puzzle input
+    pub fn new(input: &'a str) -> Self {
+        Block { text: input, offset: 0 }
+    }
+
+    pub fn text(&self) -> &'a str {
+        self.text
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.text.trim().is_empty()
+    }
+
+    /// The lines of the block with their 1-based line numbers in the whole input
+    pub fn lines(&self) -> impl Iterator<Item = (usize, &'a str)> {
+        let offset = self.offset;
+        self.text.lines().enumerate().map(move |(i, line)| (offset + i + 1, line))
+    }
+
+    /// Parses every line with `f`, locating errors at the line
+    pub fn map_lines<T>(&self, mut f: impl FnMut(&'a str) -> Result<T>) -> Result<Vec<T>> {
+        self.lines().map(|(n, line)| f(line).at_line(n, line)).collect()
+    }
+
+    /// Parses a block consisting of exactly one non-blank line
+    pub fn single_line<T>(&self, f: impl FnOnce(&'a str) -> Result<T>) -> Result<T> {
+        let mut lines = self.lines().filter(|(_, line)| !line.trim().is_empty());
+        let (n, line) = lines.next().ok_or(Error::EmptyIterator).at_line(self.offset + 1, "")?;
+        if let Some((extra, text)) = lines.next() {
+            return Err(Error::General("Expected a single line".into())).at_line(extra, text);
+        }
+        f(line).at_line(n, line)
+    }
+
+    /// Splits into the runs of non-blank lines separated by blank lines
+    pub fn blocks(&self) -> Vec<Block<'a>> {
+        let mut blocks = vec![];
+        let mut current: Option<(usize, usize, usize)> = None; // (start byte, end byte, offset)
+        let mut position = 0;
+        for (i, line) in self.text.split_inclusive('\n').enumerate() {
+            let content = line.trim_end_matches(&['\n', '\r'][..]);
+            if content.trim().is_empty() {
+                if let Some((start, end, offset)) = current.take() {
+                    blocks.push(self.block(start, end, offset));
+                }
+            } else {
+                let (start, _, offset) = current.unwrap_or((position, position, self.offset + i));
+                current = Some((start, position + content.len(), offset));
+            }
+            position += line.len();
+        }
+        if let Some((start, end, offset)) = current {
+            blocks.push(self.block(start, end, offset));
+        }
+        blocks
+    }
+
+    /// Splits into a header block and the body following the first blank line
+    pub fn header_body(&self) -> Result<(Block<'a>, Block<'a>)> {
+        let blocks = self.blocks();
+        match blocks[..] {
+            [header, first, ..] => {
+                let start = first.text.as_ptr() as usize - self.text.as_ptr() as usize;
+                Ok((header, self.block(start, self.text.len(), first.offset)))
+            }
+            _ => Err(Error::General("Expected a header and a body separated by a blank line".into()))
+                .at_line(self.offset + 1, self.text.lines().next().unwrap_or("")),
+        }
+    }
+
+    /// Splits off the first line with its line number, returning it with the block of the remaining lines
+    pub fn split_first_line(&self) -> Result<((usize, &'a str), Block<'a>)> {
+        let (first, rest) = self.text.split_once('\n').unwrap_or((self.text, ""));
+        if self.is_empty() {
+            return Err(Error::EmptyIterator).at_line(self.offset + 1, first);
+        }
+        let rest = Block {
+            text: rest,
+            offset: self.offset + 1,
+        };
+        Ok(((self.offset + 1, first.trim_end_matches('\r')), rest))
+    }
+
+    /// Parses one grid row per line, mapping every character with `f`
+    pub fn grid<T>(&self, mut f: impl FnMut(char) -> Result<T>) -> Result<Grid<T>> {
+        let mut width = None;
+        let mut cells = vec![];
+        for (n, line) in self.lines() {
+            let row = line
+                .chars()
+                .enumerate()
+                .map(|(i, ch)| f(ch).at_column(i + 1))
+                .collect::<Result<Vec<_>>>()
+                .at_line(n, line)?;
+            match width {
+                None => width = Some(row.len()),
+                Some(width) if width != row.len() => {
+                    return Err(Error::General(format!("Row has {} cells, expected {}", row.len(), width))).at_line(n, line);
+                }
+                _ => {}
+            }
+            cells.extend(row);
+        }
+
+        Grid::from_vec(width.ok_or(Error::EmptyIterator)?, cells)
+    }
+
+    /// Parses a grid of single decimal digits
+    pub fn digit_grid(&self) -> Result<Grid<u8>> {
+        self.grid(|ch| {
+            ch.to_digit(10)
+                .map(|digit| digit as u8)
+                .ok_or_else(|| Error::General(format!("Not a digit: {}", ch)))
+        })
+    }
+
+    /// Matches every line against `pattern` and builds a record from the captures with `f`
+    ///
+    /// Use [`parse_capture`] in `f` to locate errors at the offending group.
+    pub fn records<T>(&self, pattern: &Regex, mut f: impl FnMut(&Captures<'a>) -> Result<T>) -> Result<Vec<T>> {
+        self.map_lines(|line| f(&pattern.captures(line).ok_or(Error::PatternMatch)?))
+    }
+
+    fn block(&self, start: usize, end: usize, offset: usize) -> Block<'a> {
+        Block {
+            text: &self.text[start..end],
+            offset,
+        }
+    }
+}
+
+/// Parses the parts of a line split at `separator`, locating errors at the column of the part
+pub fn separated<T>(line: &str, separator: char) -> Result<Vec<T>>
+where
+    T: FromStr,
+    T::Err: Into<Error>,
+{
+    parse_parts(line, line.split(separator).map(str::trim))
+}
+
+/// Parses comma separated values like `3,4,3,1,2`
+pub fn comma_separated<T>(line: &str) -> Result<Vec<T>>
+where
+    T: FromStr,
+    T::Err: Into<Error>,
+{
+    separated(line, ',')
+}
+
+/// Parses values separated by any amount of whitespace
+pub fn whitespace_separated<T>(line: &str) -> Result<Vec<T>>
+where
+    T: FromStr,
+    T::Err: Into<Error>,
+{
+    parse_parts(line, line.split_ascii_whitespace())
+}
+
+/// Parses sub-slices of `line`, which tell the column they start at
+fn parse_parts<'a, T>(line: &'a str, parts: impl Iterator<Item = &'a str>) -> Result<Vec<T>>
+where
+    T: FromStr,
+    T::Err: Into<Error>,
+{
+    parts
+        .map(|part| part.parse::<T>().at_column(part.as_ptr() as usize - line.as_ptr() as usize + 1))
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_blocks() {
+        let input = "7,4,9\n\n1 2\n3 4\n\n\n5 6\n";
+        let (header, body) = Block::new(input).header_body().unwrap();
+        assert_eq!(header.single_line(comma_separated::<i32>).unwrap(), vec![7, 4, 9]);
+
+        let boards = body.blocks();
+        assert_eq!(boards.iter().map(|b| b.text()).collect::<Vec<_>>(), vec!["1 2\n3 4", "5 6"]);
+        assert_eq!(boards[1].lines().collect::<Vec<_>>(), vec![(7, "5 6")]);
+        assert_eq!(boards[0].map_lines(whitespace_separated::<u8>).unwrap(), vec![vec![1, 2], vec![3, 4]]);
+
+        let e = Block::new("1,2\n3,x\n").map_lines(comma_separated::<i32>).unwrap_err();
+        assert_eq!(e.to_string(), "line 2, column 3: Parse: invalid digit found in string in \"3,x\"");
+        let e = Block::new("12\n3x\n").digit_grid().unwrap_err();
+        assert_eq!(e.to_string(), "line 2, column 2: General: Not a digit: x in \"3x\"");
+        let e = Block::new("12\n345").digit_grid().unwrap_err();
+        assert_eq!(e.to_string(), "line 2: General: Row has 3 cells, expected 2 in \"345\"");
+    }
+}
@@ -0,0 +1,210 @@
+use crate::parse::comma_separated;
+use crate::prelude::*;
+use std::fmt::{Display, Formatter};
+use std::ops::{Add, AddAssign, Mul, Neg, Sub, SubAssign};
+
+/// A signed integer usable as a coordinate of a point
+pub trait Coordinate:
+    Copy + Ord + Default + Display + FromStr + Add<Output = Self> + Sub<Output = Self> + Mul<Output = Self> + Neg<Output = Self>
+{
+    fn abs(self) -> Self;
+    fn signum(self) -> Self;
+}
+
+macro_rules! coordinate {
+    ($($ty:ty),*) => {
+        $(impl Coordinate for $ty {
+            #[inline]
+            fn abs(self) -> Self {
+                <$ty>::abs(self)
+            }
+
+            #[inline]
+            fn signum(self) -> Self {
+                <$ty>::signum(self)
+            }
+        })*
+    };
+}
+
+coordinate!(i32, i64, isize);
+
+/// Declares a point type with the given coordinate fields, all operations work component by component
+macro_rules! point {
+    ($(#[$attr:meta])* $name:ident { $first:ident $(, $field:ident)* }) => {
+        $(#[$attr])*
+        #[derive(Debug, Clone, Copy, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+        pub struct $name<T> {
+            pub $first: T,
+            $(pub $field: T,)*
+        }
+
+        impl<T: Coordinate> $name<T> {
+            pub fn new($first: T $(, $field: T)*) -> Self {
+                $name { $first $(, $field)* }
+            }
+
+            /// Sum of the absolute coordinates, the Manhattan distance to the origin
+            pub fn manhattan(self) -> T {
+                self.$first.abs() $(+ self.$field.abs())*
+            }
+
+            /// Largest absolute coordinate, the Chebyshev distance to the origin
+            pub fn chebyshev(self) -> T {
+                self.$first.abs() $(.max(self.$field.abs()))*
+            }
+
+            pub fn manhattan_distance(self, other: Self) -> T {
+                (self - other).manhattan()
+            }
+
+            pub fn chebyshev_distance(self, other: Self) -> T {
+                (self - other).chebyshev()
+            }
+
+            /// The signum of every coordinate, a single step in the direction of the point
+            pub fn signum(self) -> Self {
+                $name { $first: self.$first.signum() $(, $field: self.$field.signum())* }
+            }
+
+            /// The points from here to `end`, both included, stepping by the signum of the difference
+            ///
+            /// The line to `end` has to be straight or diagonal, otherwise it ends next to `end`.
+            pub fn steps_to(self, end: Self) -> impl Iterator<Item = Self> {
+                let step = (end - self).signum();
+                let count = (end - self).chebyshev();
+                std::iter::successors(Some((self, T::default())), move |(point, i)| {
+                    (*i < count).then(|| (*point + step, *i + step.chebyshev()))
+                })
+                .map(|(point, _)| point)
+            }
+
+            /// The smallest and the largest coordinates of all points, `None` without points
+            pub fn bounding_box(points: impl IntoIterator<Item = Self>) -> Option<(Self, Self)> {
+                points.into_iter().fold(None, |bounds, p| match bounds {
+                    None => Some((p, p)),
+                    Some((min, max)) => Some((
+                        $name { $first: min.$first.min(p.$first) $(, $field: min.$field.min(p.$field))* },
+                        $name { $first: max.$first.max(p.$first) $(, $field: max.$field.max(p.$field))* },
+                    )),
+                })
+            }
+
+            /// Whether the point lies in the box spanned by `min` and `max`, both included
+            pub fn is_within(self, min: Self, max: Self) -> bool {
+                (min.$first..=max.$first).contains(&self.$first) $(&& (min.$field..=max.$field).contains(&self.$field))*
+            }
+        }
+
+        impl<T: Coordinate> Add for $name<T> {
+            type Output = Self;
+
+            #[inline]
+            fn add(self, other: Self) -> Self {
+                $name { $first: self.$first + other.$first $(, $field: self.$field + other.$field)* }
+            }
+        }
+
+        impl<T: Coordinate> Sub for $name<T> {
+            type Output = Self;
+
+            #[inline]
+            fn sub(self, other: Self) -> Self {
+                $name { $first: self.$first - other.$first $(, $field: self.$field - other.$field)* }
+            }
+        }
+
+        impl<T: Coordinate> Neg for $name<T> {
+            type Output = Self;
+
+            #[inline]
+            fn neg(self) -> Self {
+                $name { $first: -self.$first $(, $field: -self.$field)* }
+            }
+        }
+
+        impl<T: Coordinate> Mul<T> for $name<T> {
+            type Output = Self;
+
+            #[inline]
+            fn mul(self, factor: T) -> Self {
+                $name { $first: self.$first * factor $(, $field: self.$field * factor)* }
+            }
+        }
+
+        impl<T: Coordinate> AddAssign for $name<T> {
+            #[inline]
+            fn add_assign(&mut self, other: Self) {
+                *self = *self + other;
+            }
+        }
+
+        impl<T: Coordinate> SubAssign for $name<T> {
+            #[inline]
+            fn sub_assign(&mut self, other: Self) {
+                *self = *self - other;
+            }
+        }
+
+        /// Parses comma separated coordinates, like the puzzle inputs write them
+        impl<T> FromStr for $name<T>
+        where
+            T: Coordinate,
+            T::Err: Into<Error>,
+        {
+            type Err = Error;
+
+            fn from_str(s: &str) -> Result<Self> {
+                match comma_separated::<T>(s)?[..] {
+                    [$first $(, $field)*] => Ok($name { $first $(, $field)* }),
+                    ref coordinates => Err(Error::General(format!(
+                        "Expected {} coordinates, got {}",
+                        [stringify!($first) $(, stringify!($field))*].len(),
+                        coordinates.len()
+                    ))),
+                }
+            }
+        }
+
+        impl<T: Display> Display for $name<T> {
+            fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+                Display::fmt(&self.$first, f)?;
+                $(f.write_str(",")?; Display::fmt(&self.$field, f)?;)*
+                Ok(())
+            }
+        }
+    };
+}
+
+point!(
+    /// A point or vector in the plane
+    Point2 { x, y }
+);
+
+point!(
+    /// A point or vector in space
+    Point3 { x, y, z }
+);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_points() {
+        let a = Point2::new(3, -4);
+        assert_eq!((a.manhattan(), a.chebyshev(), a.signum()), (7, 4, Point2::new(1, -1)));
+        assert_eq!(-a * 2 + Point2::new(1, 1), Point2::new(-5, 9));
+        assert_eq!(a.steps_to(Point2::new(1, -2)).collect::<Vec<_>>(), vec![a, Point2::new(2, -3), Point2::new(1, -2)]);
+        assert_eq!(a.steps_to(a).count(), 1);
+
+        let points = ["1,2,3", "-1,5,0"].iter().map(|s| s.parse::<Point3<i64>>()).collect::<Result<Vec<_>>>().unwrap();
+        let (min, max) = Point3::bounding_box(points.iter().copied()).unwrap();
+        assert_eq!((min.to_string(), max.to_string()), ("-1,2,0".to_string(), "1,5,3".to_string()));
+        assert!(Point3::new(0, 3, 3).is_within(min, max) && !Point3::new(0, 3, 4).is_within(min, max));
+        assert_eq!(points[0].manhattan_distance(points[1]), 8);
+
+        assert_eq!("1,x".parse::<Point2<i32>>().unwrap_err().to_string(), "column 3: Parse: invalid digit found in string");
+        assert!("1,2,3".parse::<Point2<i32>>().is_err());
+    }
+}
@@ -0,0 +1,133 @@
+use crate::prelude::*;
+use std::io::{BufWriter, Write};
+use std::path::Path;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub struct Rgb(pub u8, pub u8, pub u8);
+
+impl Rgb {
+    pub const BLACK: Rgb = Rgb(0, 0, 0);
+    pub const WHITE: Rgb = Rgb(255, 255, 255);
+    pub const RED: Rgb = Rgb(255, 0, 0);
+    pub const YELLOW: Rgb = Rgb(255, 255, 0);
+    pub const BLUE: Rgb = Rgb(0, 0, 255);
+}
+
+/// Maps values of a range linearly onto a gradient through the given colour stops
+#[derive(Debug, Clone)]
+pub struct ColourMap {
+    min: f64,
+    max: f64,
+    stops: Vec<Rgb>,
+}
+
+impl ColourMap {
+    pub fn new(min: f64, max: f64, stops: Vec<Rgb>) -> Self {
+        assert!(!stops.is_empty(), "colour map without colours");
+        ColourMap { min, max, stops }
+    }
+
+    /// Black through red and yellow to white
+    pub fn heat(min: f64, max: f64) -> Self {
+        Self::new(min, max, vec![Rgb::BLACK, Rgb::RED, Rgb::YELLOW, Rgb::WHITE])
+    }
+
+    pub fn colour(&self, value: f64) -> Rgb {
+        let t = if self.max > self.min {
+            ((value - self.min) / (self.max - self.min)).clamp(0.0, 1.0)
+        } else {
+            0.0
+        };
+        let position = t * (self.stops.len() - 1) as f64;
+        let i = position.floor() as usize;
+        if i + 1 >= self.stops.len() {
+            return self.stops[self.stops.len() - 1];
+        }
+
+        let (from, to, t) = (self.stops[i], self.stops[i + 1], position.fract());
+        let lerp = |a: u8, b: u8| (a as f64 + (b as f64 - a as f64) * t).round() as u8;
+        Rgb(lerp(from.0, to.0), lerp(from.1, to.1), lerp(from.2, to.2))
+    }
+
+    pub fn apply<T>(&self, grid: &Grid<T>, value: impl Fn(&T) -> f64) -> Image {
+        Image::Pixmap(grid.map(|cell| self.colour(value(cell))))
+    }
+}
+
+/// A Netpbm image, written in the binary format matching its variant
+pub enum Image {
+    /// PBM, set pixels are black
+    Bitmap(Grid<bool>),
+    /// PGM with a maximum value of 255
+    Greymap(Grid<u8>),
+    /// PPM
+    Pixmap(Grid<Rgb>),
+}
+
+impl Image {
+    pub fn extension(&self) -> &'static str {
+        match self {
+            Image::Bitmap(_) => "pbm",
+            Image::Greymap(_) => "pgm",
+            Image::Pixmap(_) => "ppm",
+        }
+    }
+
+    /// File name of the image rendered for a part of a day, like `day13_part2.pbm`
+    pub fn file_name(&self, day: usize, part: usize) -> String {
+        format!("day{}_part{}.{}", day, part, self.extension())
+    }
+
+    pub fn write(&self, w: &mut impl Write) -> std::io::Result<()> {
+        match self {
+            Image::Bitmap(grid) => {
+                write!(w, "P4\n{} {}\n", grid.width(), grid.height())?;
+                for row in grid.rows() {
+                    let bytes = row
+                        .chunks(8)
+                        .map(|chunk| chunk.iter().enumerate().fold(0_u8, |a, (i, b)| a | ((*b as u8) << (7 - i))))
+                        .collect::<Vec<_>>();
+                    w.write_all(&bytes)?;
+                }
+            }
+            Image::Greymap(grid) => {
+                write!(w, "P5\n{} {}\n255\n", grid.width(), grid.height())?;
+                w.write_all(grid.cells())?;
+            }
+            Image::Pixmap(grid) => {
+                write!(w, "P6\n{} {}\n255\n", grid.width(), grid.height())?;
+                for Rgb(r, g, b) in grid.cells() {
+                    w.write_all(&[*r, *g, *b])?;
+                }
+            }
+        }
+        Ok(())
+    }
+
+    pub fn save(&self, path: impl AsRef<Path>) -> Result<()> {
+        let mut w = BufWriter::new(std::fs::File::create(path)?);
+        self.write(&mut w)?;
+        w.flush()?;
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_write() {
+        let grid = Grid::from_vec(10, (0..20).map(|i| i % 3 == 0).collect()).unwrap();
+        let mut pbm = vec![];
+        Image::Bitmap(grid).write(&mut pbm).unwrap();
+        assert_eq!(pbm, b"P4\n10 2\n\x92\x40\x24\x80");
+
+        let colours = ColourMap::new(0.0, 2.0, vec![Rgb::BLACK, Rgb::WHITE]);
+        assert_eq!(colours.colour(1.0), Rgb(128, 128, 128));
+        assert_eq!(colours.colour(5.0), Rgb::WHITE);
+        let mut ppm = vec![];
+        colours.apply(&Grid::new(1, 1, 2), |v| *v as f64).write(&mut ppm).unwrap();
+        assert_eq!(ppm, b"P6\n1 1\n255\n\xff\xff\xff");
+    }
+}
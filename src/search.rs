@@ -0,0 +1,193 @@
+use std::cmp::Reverse;
+use std::collections::hash_map::Entry;
+use std::collections::{BinaryHeap, HashMap};
+use std::hash::Hash;
+use std::ops::Add;
+
+/// Counters describing the work done by a search
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct Stats {
+    /// States taken from the queue whose neighbours were generated
+    pub expanded: usize,
+    /// Entries pushed onto the queue, including ones superseded later by a cheaper path
+    pub pushed: usize,
+    /// Largest number of entries in the queue at any time
+    pub peak_queue: usize,
+}
+
+/// A cheapest path found by [`dijkstra`] or [`astar`]
+#[derive(Debug, Clone)]
+pub struct Path<S, C> {
+    pub cost: C,
+    /// The states from the start to the goal, both included
+    pub states: Vec<S>,
+    pub stats: Stats,
+}
+
+struct Node<S, C> {
+    state: S,
+    parent: Option<usize>,
+    cost: C,
+    closed: bool,
+}
+
+/// Finds the cheapest path from `start` to a state satisfying `is_goal`
+///
+/// `neighbours` yields the states reachable in one step together with the cost of the step.
+pub fn dijkstra<S, C, N, I, G>(start: S, neighbours: N, is_goal: G) -> Option<Path<S, C>>
+where
+    S: Clone + Eq + Hash,
+    C: Copy + Ord + Default + Add<Output = C>,
+    N: FnMut(&S) -> I,
+    I: IntoIterator<Item = (S, C)>,
+    G: FnMut(&S) -> bool,
+{
+    astar(start, neighbours, |_| C::default(), is_goal)
+}
+
+/// Like [`dijkstra`], but states with the same `key` count as one, the first one taken from the queue wins
+pub fn dijkstra_by_key<S, K, C, F, N, I, G>(start: S, key: F, neighbours: N, is_goal: G) -> Option<Path<S, C>>
+where
+    S: Clone,
+    K: Eq + Hash,
+    C: Copy + Ord + Default + Add<Output = C>,
+    F: FnMut(&S) -> K,
+    N: FnMut(&S) -> I,
+    I: IntoIterator<Item = (S, C)>,
+    G: FnMut(&S) -> bool,
+{
+    astar_by_key(start, key, neighbours, |_| C::default(), is_goal)
+}
+
+/// Like [`dijkstra`], but guided towards the goal by a `heuristic` estimating the remaining cost
+///
+/// The heuristic must be consistent: it never drops by more than the cost of a step, and is zero at the goal.
+pub fn astar<S, C, N, I, H, G>(start: S, neighbours: N, heuristic: H, is_goal: G) -> Option<Path<S, C>>
+where
+    S: Clone + Eq + Hash,
+    C: Copy + Ord + Default + Add<Output = C>,
+    N: FnMut(&S) -> I,
+    I: IntoIterator<Item = (S, C)>,
+    H: FnMut(&S) -> C,
+    G: FnMut(&S) -> bool,
+{
+    astar_by_key(start, S::clone, neighbours, heuristic, is_goal)
+}
+
+/// Like [`astar`], but states with the same `key` count as one, the first one taken from the queue wins
+pub fn astar_by_key<S, K, C, F, N, I, H, G>(
+    start: S,
+    mut key: F,
+    mut neighbours: N,
+    mut heuristic: H,
+    mut is_goal: G,
+) -> Option<Path<S, C>>
+where
+    S: Clone,
+    K: Eq + Hash,
+    C: Copy + Ord + Default + Add<Output = C>,
+    F: FnMut(&S) -> K,
+    N: FnMut(&S) -> I,
+    I: IntoIterator<Item = (S, C)>,
+    H: FnMut(&S) -> C,
+    G: FnMut(&S) -> bool,
+{
+    let mut stats = Stats::default();
+
+    // every key seen so far has an index into nodes, where its cheapest known state, cost and parent are kept
+    let mut indices = HashMap::new();
+    let mut nodes = vec![];
+    let mut queue = BinaryHeap::new();
+
+    indices.insert(key(&start), 0);
+    queue.push((Reverse(heuristic(&start)), C::default(), 0));
+    nodes.push(Node {
+        state: start,
+        parent: None,
+        cost: C::default(),
+        closed: false,
+    });
+    stats.pushed = 1;
+    stats.peak_queue = 1;
+
+    // ties are broken by the highest cost so far, which is the closest to the goal
+    while let Some((_, _, i)) = queue.pop() {
+        if nodes[i].closed {
+            continue;
+        }
+        nodes[i].closed = true;
+
+        if is_goal(&nodes[i].state) {
+            let mut states = vec![];
+            let mut current = Some(i);
+            while let Some(i) = current {
+                states.push(nodes[i].state.clone());
+                current = nodes[i].parent;
+            }
+            states.reverse();
+            return Some(Path {
+                cost: nodes[i].cost,
+                states,
+                stats,
+            });
+        }
+
+        stats.expanded += 1;
+        let cost = nodes[i].cost;
+        for (next, step) in neighbours(&nodes[i].state) {
+            let next_cost = cost + step;
+            let j = match indices.entry(key(&next)) {
+                Entry::Occupied(entry) => {
+                    let node = &mut nodes[*entry.get()];
+                    if node.closed || next_cost >= node.cost {
+                        continue;
+                    }
+                    node.state = next;
+                    node.cost = next_cost;
+                    node.parent = Some(i);
+                    *entry.get()
+                }
+                Entry::Vacant(entry) => {
+                    nodes.push(Node {
+                        state: next,
+                        parent: Some(i),
+                        cost: next_cost,
+                        closed: false,
+                    });
+                    *entry.insert(nodes.len() - 1)
+                }
+            };
+
+            queue.push((Reverse(next_cost + heuristic(&nodes[j].state)), next_cost, j));
+            stats.pushed += 1;
+            stats.peak_queue = stats.peak_queue.max(queue.len());
+        }
+    }
+
+    None
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_search() {
+        // 0 -> 1 -> 3 is cheaper than the direct edge 0 -> 3
+        let edges = [(0, 1, 1), (0, 2, 4), (0, 3, 5), (1, 3, 2), (2, 3, 1)];
+        let neighbours = |s: &usize| edges.iter().filter(move |e| e.0 == *s).map(|e| (e.1, e.2)).collect::<Vec<_>>();
+
+        let path = dijkstra(0, neighbours, |s| *s == 3).unwrap();
+        assert_eq!((path.cost, path.states), (3, vec![0, 1, 3]));
+        assert_eq!(path.stats.expanded, 2);
+
+        let path = astar(0, neighbours, |s| if *s == 3 { 0 } else { 1 }, |s| *s == 3).unwrap();
+        assert_eq!(path.cost, 3);
+        assert!(dijkstra(3, neighbours, |s| *s == 0).is_none());
+
+        // keyed by the node alone, the cheaper of the two ways into 3 is kept
+        let tagged = |(s, _): &(usize, usize)| neighbours(s).into_iter().map(|(t, c)| ((t, *s), c)).collect::<Vec<_>>();
+        let path = dijkstra_by_key((0, 0), |(s, _)| *s, tagged, |(s, _)| *s == 3).unwrap();
+        assert_eq!((path.cost, path.states), (3, vec![(0, 0), (1, 0), (3, 1)]));
+    }
+}
@@ -0,0 +1,138 @@
+use crate::grid::{NEIGHBOURS4, NEIGHBOURS8};
+use crate::prelude::*;
+use std::collections::HashMap;
+use std::fmt::{Display, Formatter};
+
+pub type Position = Point2<isize>;
+
+/// An unbounded grid storing only the cells which differ from a background value
+///
+/// The bounding box covers all cells set so far, it does not shrink when cells are reset to the background.
+#[derive(Debug, Clone)]
+pub struct SparseGrid<T> {
+    cells: HashMap<Position, T>,
+    background: T,
+    bounds: Option<(Position, Position)>,
+}
+
+impl<T: Clone + PartialEq> SparseGrid<T> {
+    pub fn new(background: T) -> Self {
+        SparseGrid {
+            cells: HashMap::new(),
+            background,
+            bounds: None,
+        }
+    }
+
+    /// Copies the cells of a dense grid which differ from the background, with its top left corner at the origin
+    pub fn from_grid(grid: &Grid<T>, background: T) -> Self {
+        let mut sparse = Self::new(background);
+        for ((x, y), value) in grid.iter() {
+            sparse.set(Position::new(x as isize, y as isize), value.clone());
+        }
+        sparse
+    }
+
+    /// The value of all cells which were not set
+    pub fn background(&self) -> &T {
+        &self.background
+    }
+
+    /// Changes the value of all cells which were not set
+    pub fn set_background(&mut self, background: T) {
+        self.background = background;
+        let background = &self.background;
+        self.cells.retain(|_, value| value != background);
+    }
+
+    pub fn get(&self, position: Position) -> &T {
+        self.cells.get(&position).unwrap_or(&self.background)
+    }
+
+    pub fn set(&mut self, position: Position, value: T) {
+        if value == self.background {
+            self.cells.remove(&position);
+        } else {
+            self.cells.insert(position, value);
+            self.bounds = Some(match self.bounds {
+                None => (position, position),
+                Some((min, max)) => (
+                    Position::new(min.x.min(position.x), min.y.min(position.y)),
+                    Position::new(max.x.max(position.x), max.y.max(position.y)),
+                ),
+            });
+        }
+    }
+
+    /// Changes a cell in place, starting from its current value
+    pub fn update(&mut self, position: Position, f: impl FnOnce(&mut T)) {
+        let mut value = self.get(position).clone();
+        f(&mut value);
+        self.set(position, value);
+    }
+
+    /// The smallest and the largest position set, `None` while all cells have the background value
+    pub fn bounds(&self) -> Option<(Position, Position)> {
+        self.bounds
+    }
+
+    /// The number of cells which differ from the background
+    pub fn len(&self) -> usize {
+        self.cells.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.cells.is_empty()
+    }
+
+    /// The cells which differ from the background, in no particular order
+    pub fn iter(&self) -> impl Iterator<Item = (Position, &T)> {
+        self.cells.iter().map(|(position, value)| (*position, value))
+    }
+
+    pub fn neighbours4(&self, position: Position) -> impl Iterator<Item = Position> {
+        NEIGHBOURS4.iter().map(move |(dx, dy)| position + Position::new(*dx, *dy))
+    }
+
+    pub fn neighbours8(&self, position: Position) -> impl Iterator<Item = Position> {
+        NEIGHBOURS8.iter().map(move |(dx, dy)| position + Position::new(*dx, *dy))
+    }
+
+    /// A dense copy of the bounding box, so that sparse grids are printed and rendered like dense ones
+    pub fn to_grid(&self) -> Grid<T> {
+        match self.bounds {
+            Some((min, max)) => Grid::from_fn((max.x - min.x + 1) as usize, (max.y - min.y + 1) as usize, |x, y| {
+                self.get(min + Position::new(x as isize, y as isize)).clone()
+            }),
+            None => Grid::new(0, 0, self.background.clone()),
+        }
+    }
+}
+
+/// Writes the bounding box like a dense [`Grid`]
+impl<T: Clone + PartialEq + Display> Display for SparseGrid<T> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        Display::fmt(&self.to_grid(), f)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_sparse_grid() {
+        let mut grid = SparseGrid::new('.');
+        grid.set(Position::new(-2, 1), '#');
+        grid.set(Position::new(1, -1), '#');
+        grid.update(Position::new(0, 0), |c| *c = '+');
+        assert_eq!((grid.len(), *grid.get(Position::new(100, 100))), (3, '.'));
+        assert_eq!(grid.bounds(), Some((Position::new(-2, -1), Position::new(1, 1))));
+        assert_eq!(grid.to_string(), "...#\n..+.\n#...");
+
+        grid.set(Position::new(0, 0), '.');
+        grid.set_background('#');
+        assert_eq!((grid.len(), grid.to_string()), (0, "####\n####\n####".to_string()));
+        assert_eq!(grid.neighbours4(Position::default()).count(), 4);
+    }
+}
@@ -0,0 +1,234 @@
+use crate::prelude::*;
+use std::io::{BufRead, Write};
+use std::path::Path;
+use std::sync::mpsc::{self, Receiver, RecvTimeoutError};
+use std::time::Duration;
+
+/// A puzzle state which evolves step by step, like the octopuses of day 11
+pub trait Simulation {
+    /// Advances the simulation by a single step, returns false once nothing changed anymore
+    fn step(&mut self) -> bool;
+
+    /// The current state as text for the terminal
+    fn render(&self) -> String;
+
+    /// The current state as an image, headless frames are written as text without one
+    fn image(&self) -> Option<Image> {
+        None
+    }
+}
+
+/// Plays a [`Simulation`] in the terminal or writes its frames into a directory
+///
+/// In the terminal, commands are read line by line from stdin:
+/// an empty line single-steps while paused, `p` toggles the pause, `g N` (or just `N`) jumps to step N,
+/// `+` and `-` double or halve the frame rate and `q` quits.
+#[derive(Debug, Clone)]
+pub struct Visualizer {
+    fps: f64,
+    paused: bool,
+    jump: usize,
+    max_steps: usize,
+}
+
+impl Default for Visualizer {
+    fn default() -> Self {
+        Visualizer {
+            fps: 10.0,
+            paused: false,
+            jump: 0,
+            max_steps: 1000,
+        }
+    }
+}
+
+impl Visualizer {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Frames per second while playing
+    pub fn fps(mut self, fps: f64) -> Self {
+        self.fps = fps;
+        self
+    }
+
+    /// Whether to start paused, waiting for single steps
+    pub fn paused(mut self, paused: bool) -> Self {
+        self.paused = paused;
+        self
+    }
+
+    /// Skips the first `step` steps without showing or writing them
+    pub fn jump(mut self, step: usize) -> Self {
+        self.jump = step;
+        self
+    }
+
+    /// Stops after `steps` steps, even if the simulation keeps changing
+    pub fn max_steps(mut self, steps: usize) -> Self {
+        self.max_steps = steps;
+        self
+    }
+
+    /// Runs a single step, false once the simulation came to rest or hit the step limit
+    fn advance(&self, simulation: &mut dyn Simulation, step: &mut usize) -> bool {
+        if *step >= self.max_steps {
+            return false;
+        }
+        *step += 1;
+        simulation.step() && *step < self.max_steps
+    }
+
+    /// Steps forward up to step `target`, returns whether the simulation is still active
+    fn skip_to(&self, simulation: &mut dyn Simulation, step: &mut usize, target: usize) -> bool {
+        let mut active = true;
+        while active && *step < target {
+            active = self.advance(simulation, step);
+        }
+        active
+    }
+
+    /// Plays the simulation in the terminal until it comes to rest or is quit, returns the last step shown
+    pub fn run(&self, simulation: &mut dyn Simulation) -> Result<usize> {
+        let commands = read_commands();
+        let (mut fps, mut paused) = (self.fps, self.paused);
+        let mut message = String::new();
+        let mut step = 0;
+        let mut active = self.skip_to(simulation, &mut step, self.jump);
+
+        loop {
+            let state = if !active {
+                "stopped"
+            } else if paused {
+                "paused"
+            } else {
+                "playing"
+            };
+            let mut stdout = std::io::stdout();
+            write!(
+                stdout,
+                "\x1b[H\x1b[2J{}\nstep {} | {} fps | {} | enter: step, p: pause, g N: jump, +/-: speed, q: quit\n{}",
+                simulation.render(),
+                step,
+                fps,
+                state,
+                message,
+            )?;
+            stdout.flush()?;
+            message.clear();
+
+            if !active {
+                break;
+            }
+
+            let frame = Duration::from_secs_f64(1.0 / fps);
+            let command = if paused {
+                match commands.recv() {
+                    Ok(command) => Some(command),
+                    // nothing can unpause once stdin is closed
+                    Err(_) => break,
+                }
+            } else {
+                match commands.recv_timeout(frame) {
+                    Ok(command) => Some(command),
+                    Err(RecvTimeoutError::Timeout) => None,
+                    Err(RecvTimeoutError::Disconnected) => {
+                        std::thread::sleep(frame);
+                        None
+                    }
+                }
+            };
+
+            match command.as_deref().map(str::trim) {
+                None => active = self.advance(simulation, &mut step),
+                Some("q") => break,
+                Some("p") => paused = !paused,
+                Some("") => {
+                    if paused {
+                        active = self.advance(simulation, &mut step);
+                    }
+                }
+                Some("+") => fps *= 2.0,
+                Some("-") => fps /= 2.0,
+                Some(command) => match usize::from_str(command.strip_prefix("g ").unwrap_or(command).trim()) {
+                    Ok(target) if target >= step => active = self.skip_to(simulation, &mut step, target),
+                    Ok(target) => message = format!("Cannot go back to step {}", target),
+                    Err(_) => message = format!("Unknown command {}", command),
+                },
+            }
+        }
+
+        println!();
+        Ok(step)
+    }
+
+    /// Writes a frame per step into `dir`, as long as the simulation changes, returns the last step written
+    ///
+    /// Frames are named `frame00042.txt`, or get the extension of their [`Image`].
+    pub fn write_frames(&self, simulation: &mut dyn Simulation, dir: &Path) -> Result<usize> {
+        std::fs::create_dir_all(dir)?;
+
+        let mut step = 0;
+        let mut active = self.skip_to(simulation, &mut step, self.jump);
+        loop {
+            match simulation.image() {
+                Some(image) => image.save(dir.join(format!("frame{:05}.{}", step, image.extension())))?,
+                None => std::fs::write(dir.join(format!("frame{:05}.txt", step)), simulation.render() + "\n")?,
+            }
+
+            if !active {
+                break;
+            }
+            active = self.advance(simulation, &mut step);
+        }
+
+        Ok(step)
+    }
+}
+
+/// Forwards the lines typed on stdin, the reader thread ends with stdin
+fn read_commands() -> Receiver<String> {
+    let (sender, receiver) = mpsc::channel();
+    std::thread::spawn(move || {
+        for line in std::io::stdin().lock().lines() {
+            if line.map_or(true, |line| sender.send(line).is_err()) {
+                break;
+            }
+        }
+    });
+    receiver
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    struct Countdown(usize);
+
+    impl Simulation for Countdown {
+        fn step(&mut self) -> bool {
+            self.0 = self.0.saturating_sub(1);
+            self.0 > 0
+        }
+
+        fn render(&self) -> String {
+            self.0.to_string()
+        }
+    }
+
+    #[test]
+    fn test_write_frames() {
+        let dir = std::env::temp_dir().join(format!("aoc_frames_{}", std::process::id()));
+
+        let last = Visualizer::new().jump(2).write_frames(&mut Countdown(5), &dir).unwrap();
+        let mut frames = std::fs::read_dir(&dir).unwrap().map(|entry| entry.unwrap().file_name()).collect::<Vec<_>>();
+        frames.sort();
+        assert_eq!(last, 5);
+        assert_eq!(frames, ["frame00002.txt", "frame00003.txt", "frame00004.txt", "frame00005.txt"]);
+        assert_eq!(std::fs::read_to_string(dir.join("frame00004.txt")).unwrap(), "1\n");
+
+        assert_eq!(Visualizer::new().max_steps(3).write_frames(&mut Countdown(5), &dir).unwrap(), 3);
+        std::fs::remove_dir_all(&dir).unwrap();
+    }
+}
@@ -0,0 +1,80 @@
+//! Checks every entry of `data/answers.txt` against the solutions in the registry
+
+use adventofcode2021::input::{data_dir, Input};
+use adventofcode2021::{read_lines, Error, Result, DAYS};
+use std::str::FromStr;
+
+struct Answer {
+    line: usize,
+    day: usize,
+    part: usize,
+    file: String,
+    expected: String,
+    slow: bool,
+}
+
+fn parse_answer(line: usize, text: &str) -> Result<Answer> {
+    let fields = text.split_ascii_whitespace().collect::<Vec<_>>();
+    let (day, part, file, expected, slow) = match fields[..] {
+        [day, part, file, expected] => (day, part, file, expected, false),
+        [day, part, file, expected, "slow"] => (day, part, file, expected, true),
+        _ => return Err(Error::General(format!("answers.txt:{}: invalid entry {}", line, text))),
+    };
+
+    let day = usize::from_str(day)?;
+    let part = usize::from_str(part)?;
+    if !(1..=DAYS.len()).contains(&day) || !(1..=DAYS[day - 1].parts).contains(&part) {
+        return Err(Error::General(format!("answers.txt:{}: unknown day {} part {}", line, day, part)));
+    }
+
+    Ok(Answer {
+        line,
+        day,
+        part,
+        file: file.to_string(),
+        expected: expected.replace("\\n", "\n"),
+        slow,
+    })
+}
+
+fn answers() -> Result<Vec<Answer>> {
+    read_lines(data_dir().join("answers.txt"))?
+        .iter()
+        .enumerate()
+        .filter(|(_, text)| !text.trim().is_empty() && !text.starts_with('#'))
+        .map(|(i, text)| parse_answer(i + 1, text))
+        .collect()
+}
+
+/// Runs all entries with the given speed, reporting every wrong answer at once
+fn check(slow: bool) {
+    let failures = answers()
+        .unwrap()
+        .iter()
+        .filter(|answer| answer.slow == slow)
+        .filter_map(|answer| {
+            let result = Input::Path(data_dir().join(&answer.file))
+                .read()
+                .and_then(|input| (DAYS[answer.day - 1].solve)(&input, answer.part));
+            let location = format!("answers.txt:{}: day {} part {} on {}", answer.line, answer.day, answer.part, answer.file);
+            match result {
+                Ok(actual) if actual == answer.expected => None,
+                Ok(actual) => Some(format!("{}: expected {}, got {}", location, answer.expected, actual)),
+                Err(e) => Some(format!("{}: {}", location, e)),
+            }
+        })
+        .collect::<Vec<_>>();
+
+    assert!(failures.is_empty(), "{} wrong answers\n{}", failures.len(), failures.join("\n"));
+}
+
+#[test]
+fn test_answers() {
+    check(false);
+}
+
+#[test]
+#[ignore]
+fn test_slow_answers() {
+    check(true);
+}
@@ -0,0 +1,33 @@
+//! Uses the solvers as a library, the way other crates would
+
+use adventofcode2021::day16::{evaluate, parse_hex, parse_packets, Packet};
+use adventofcode2021::day18::SnailfishNumber;
+use adventofcode2021::day24::{CpuState, Register};
+
+#[test]
+fn test_packets() {
+    let mut bits = parse_hex("D2FE28").unwrap().into_iter();
+    assert_eq!(parse_packets(&mut bits, None), vec![Packet::Number { version: 6, value: 2021 }]);
+
+    let packets = adventofcode2021::day16::parse("9C0141080250320F1802104A08").unwrap();
+    assert_eq!(evaluate(&packets[0]), 1);
+    assert!(parse_hex("D2FX28").is_err());
+}
+
+#[test]
+fn test_snailfish_numbers() {
+    let number = |s: &str| Box::new(s.parse::<SnailfishNumber>().unwrap());
+    let sum = SnailfishNumber::add_list(["[1,1]", "[2,2]", "[3,3]", "[4,4]"].into_iter().map(number)).unwrap();
+    assert_eq!((sum.to_string(), sum.magnitude()), ("[[[[1,1],[2,2]],[3,3]],[4,4]]".to_string(), 445));
+    assert_eq!((number("[1,2]") + number("[[3,4],5]")).magnitude(), 143);
+    assert!("[1,2]]".parse::<SnailfishNumber>().is_err());
+}
+
+#[test]
+fn test_alu() {
+    // z is 1 if the second input is three times the first one
+    let program = adventofcode2021::day24::parse("inp z\ninp x\nmul z 3\neql z x").unwrap();
+    let mut cpu = CpuState::default();
+    cpu.execute(&program, &[2, 6, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0]).unwrap();
+    assert_eq!((cpu.register(Register::Z), cpu.valid_state()), (1, false));
+}